@@ -0,0 +1,714 @@
+//! Chord module for handling musical chords
+//!
+//! A chord is a set of pitches sounding together. This module represents a chord by its
+//! root pitch and the intervals of the remaining chord tones above that root, which keeps
+//! the chord's structure independent of the key it is played in.
+
+use super::{Interval, Pitch, PitchClass, PitchClassSet, PitchRange, SEMITONES_IN_OCTAVE, Step};
+
+use crate::scales::{ScaleInPitches, ScaleQuality};
+use crate::{
+    ALTO_RANGE, BASS_RANGE, MAJOR_THIRD, MINOR_SEVENTH, MINOR_THIRD, PERFECT_FIFTH, SOPRANO_RANGE,
+    TENOR_RANGE,
+};
+
+/// Simple intervals above the root that become tensions an octave higher: the flat,
+/// natural and sharp ninth, the natural and sharp eleventh, and the flat and natural
+/// thirteenth.
+const TENSION_SEMITONES: [u8; 7] = [1, 2, 3, 5, 6, 8, 9];
+
+/// Error returned when a chord operation cannot be carried out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChordError {
+    /// A pitch of the resulting chord would be outside the MIDI range (0-127)
+    OutOfRange,
+    /// A voice of the resulting voicing would be outside its typical range
+    OutOfVoiceRange,
+    /// The chord has more tones than the voices can sing
+    TooManyTones,
+}
+
+impl std::fmt::Display for ChordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfRange => write!(f, "chord pitch out of MIDI range"),
+            Self::OutOfVoiceRange => write!(f, "chord voice out of its typical range"),
+            Self::TooManyTones => write!(f, "chord has more tones than voices"),
+        }
+    }
+}
+
+impl std::error::Error for ChordError {}
+
+/// The quality of a triad, determined by its two stacked thirds.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TriadQuality {
+    /// A major third followed by a minor third
+    Major,
+    /// A minor third followed by a major third
+    Minor,
+    /// Two stacked minor thirds
+    Diminished,
+    /// Two stacked major thirds
+    Augmented,
+}
+
+/// Classifies a triad given in root position by its two stacked thirds.
+///
+/// # Arguments
+///
+/// * `pitches` - The root, third and fifth of the triad, in ascending order
+///
+/// # Returns
+///
+/// `Some` with the quality of the triad, or `None` if the pitches are not two stacked
+/// major or minor thirds
+pub fn triad_quality(pitches: &[Pitch; 3]) -> Option<TriadQuality> {
+    let lower = pitches[1].semitones().checked_sub(pitches[0].semitones())?;
+    let upper = pitches[2].semitones().checked_sub(pitches[1].semitones())?;
+
+    match (Interval::new(lower), Interval::new(upper)) {
+        (MAJOR_THIRD, MINOR_THIRD) => Some(TriadQuality::Major),
+        (MINOR_THIRD, MAJOR_THIRD) => Some(TriadQuality::Minor),
+        (MINOR_THIRD, MINOR_THIRD) => Some(TriadQuality::Diminished),
+        (MAJOR_THIRD, MAJOR_THIRD) => Some(TriadQuality::Augmented),
+        _ => None,
+    }
+}
+
+/// Represents a musical chord as a root pitch and the intervals of its chord tones above the root.
+///
+/// For example, a C major triad has the root C4 and the intervals
+/// `[MAJOR_THIRD, PERFECT_FIFTH]`. A slash chord additionally has a bass pitch sounding
+/// below the chord, see [`Chord::with_bass`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chord {
+    root: Pitch,
+    intervals: Vec<Interval>,
+    bass: Option<Pitch>,
+}
+
+impl Chord {
+    /// Creates a new chord from a root and the intervals of its chord tones above the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root pitch of the chord
+    /// * `intervals` - The intervals of the other chord tones, measured from the root
+    ///
+    /// # Returns
+    ///
+    /// A new Chord with the specified root and intervals
+    #[inline]
+    pub fn new(root: Pitch, intervals: Vec<Interval>) -> Self {
+        Self {
+            root,
+            intervals,
+            bass: None,
+        }
+    }
+
+    /// Returns a slash chord with a bass pitch sounding below the chord.
+    ///
+    /// The bass need not be a chord tone, e.g. C/E or C/D. A bass given at or above the
+    /// root is moved down by octaves until it sounds below the root, keeping its pitch
+    /// class. When the root is in the lowest MIDI octave there may be no such pitch, and
+    /// the bass is then placed in that lowest octave.
+    ///
+    /// # Arguments
+    ///
+    /// * `bass` - The bass pitch
+    ///
+    /// # Returns
+    ///
+    /// The chord with the same root and intervals over the given bass
+    pub fn with_bass(&self, bass: Pitch) -> Chord {
+        let mut bass = bass.semitones();
+        while bass >= self.root.semitones() && bass >= SEMITONES_IN_OCTAVE {
+            bass -= SEMITONES_IN_OCTAVE;
+        }
+
+        Self {
+            bass: Some(Pitch::new(bass)),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the root pitch of the chord.
+    ///
+    /// # Returns
+    ///
+    /// The root pitch
+    #[inline]
+    pub const fn root(&self) -> Pitch {
+        self.root
+    }
+
+    /// Returns the bass pitch of a slash chord.
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the bass pitch, or `None` if the chord has no separate bass
+    #[inline]
+    pub const fn bass(&self) -> Option<Pitch> {
+        self.bass
+    }
+
+    /// Returns the intervals of the chord tones above the root.
+    ///
+    /// # Returns
+    ///
+    /// A slice of intervals, measured from the root
+    #[inline]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Transposes the chord up by an interval.
+    ///
+    /// The root (and the bass of a slash chord) move by the interval, while the chord
+    /// tones keep their intervals above the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval to transpose the chord up by
+    ///
+    /// # Returns
+    ///
+    /// The transposed chord, or `ChordError::OutOfRange` if any of its pitches would be
+    /// above the MIDI range
+    pub fn transpose(&self, interval: Interval) -> Result<Chord, ChordError> {
+        let shift = |pitch: Pitch| {
+            pitch
+                .semitones()
+                .checked_add(interval.semitones())
+                .filter(|semitones| *semitones <= Pitch::MAX.semitones())
+                .map(Pitch::new)
+                .ok_or(ChordError::OutOfRange)
+        };
+
+        if let Some(highest) = self.pitches().into_iter().max() {
+            shift(highest)?;
+        }
+
+        Ok(Self {
+            root: shift(self.root)?,
+            intervals: self.intervals.clone(),
+            bass: self.bass.map(shift).transpose()?,
+        })
+    }
+
+    /// Returns the chord's intervals above the root for use with scale machinery.
+    ///
+    /// A chord can be viewed as a partial scale whose degrees are measured from the root.
+    ///
+    /// # Returns
+    ///
+    /// The intervals of the chord tones above the root, in the order they were given
+    pub fn to_interval_scale(&self) -> Vec<Interval> {
+        self.intervals.clone()
+    }
+
+    /// Returns the pitches of the chord in root position.
+    ///
+    /// # Returns
+    ///
+    /// The bass of a slash chord, if any, then the root followed by every chord tone
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.bass.into_iter().chain(self.chord_tones()).collect()
+    }
+
+    /// Returns the root followed by every chord tone, leaving out the bass of a slash chord
+    fn chord_tones(&self) -> impl Iterator<Item = Pitch> + '_ {
+        ::std::iter::once(self.root).chain(
+            self.intervals
+                .iter()
+                .map(|interval| self.root + Step::from(*interval)),
+        )
+    }
+
+    /// Returns the pitches of an inversion of the chord.
+    ///
+    /// The `n`-th inversion moves the lowest `n` chord tones up by as many octaves as
+    /// needed to sit above the remaining tones. Inversion 0 is the root position.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The inversion number
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the pitches of the inversion in ascending order, or `None` if `n` is not
+    /// smaller than the number of chord tones. The bass of a slash chord is not part of
+    /// the inversion.
+    pub fn inversion(&self, n: usize) -> Option<Vec<Pitch>> {
+        let mut pitches: Vec<Pitch> = self.chord_tones().collect();
+        if n >= pitches.len() {
+            return None;
+        }
+
+        pitches.rotate_left(n);
+
+        let octave = Step::new(SEMITONES_IN_OCTAVE);
+        for i in 1..pitches.len() {
+            while pitches[i] <= pitches[i - 1] {
+                pitches[i] = pitches[i] + octave;
+            }
+        }
+
+        Some(pitches)
+    }
+
+    /// Voices the chord for four parts: soprano, alto, tenor and bass.
+    ///
+    /// The bass is kept as given and must lie in the bass range. The remaining chord tones
+    /// are sung by the upper three voices: the root is doubled when fewer than three are
+    /// left, and the fifth is omitted when four are left, e.g. a seventh chord over a bass
+    /// that is not a chord tone. The tones are stacked in close position above the bass
+    /// so the upper three voices stay within an octave, and the stack is raised by octaves
+    /// until the soprano reaches its range. Every ordering of the upper tones is tried,
+    /// starting with the chord's own order and its rotations, and the first one that keeps
+    /// every voice within its typical range is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `bass` - The pitch sung by the bass
+    ///
+    /// # Returns
+    ///
+    /// The bass, tenor, alto and soprano pitches in ascending order, or an error:
+    /// - `ChordError::TooManyTones` if more than three tones are left for the upper voices
+    ///   after omitting the fifth
+    /// - `ChordError::OutOfVoiceRange` if the bass is outside the bass range, or no
+    ///   ordering of the upper tones keeps the tenor, alto and soprano within their ranges
+    pub fn distribute_satb(&self, bass: Pitch) -> Result<[Pitch; 4], ChordError> {
+        const ORDERINGS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [1, 0, 2],
+            [2, 1, 0],
+        ];
+
+        if !BASS_RANGE.contains(bass) {
+            return Err(ChordError::OutOfVoiceRange);
+        }
+
+        let octave = u16::from(SEMITONES_IN_OCTAVE);
+        let pitch_class = |pitch: Pitch| u16::from(pitch.semitones()) % octave;
+
+        let mut upper: Vec<u16> = Vec::new();
+        for tone in self.chord_tones().map(pitch_class) {
+            if !upper.contains(&tone) {
+                upper.push(tone);
+            }
+        }
+        upper.retain(|tone| *tone != pitch_class(bass));
+        if upper.len() > 3 {
+            let fifth = (pitch_class(self.root) + u16::from(PERFECT_FIFTH.semitones())) % octave;
+            upper.retain(|tone| *tone != fifth);
+        }
+        if upper.len() > 3 {
+            return Err(ChordError::TooManyTones);
+        }
+        while upper.len() < 3 {
+            upper.push(pitch_class(self.root));
+        }
+
+        let lowest_above =
+            |tone: u16, floor: u16| floor + (tone + octave - floor % octave) % octave;
+        let in_range = |semitones: u16, range: PitchRange| {
+            u8::try_from(semitones)
+                .ok()
+                .map(Pitch::new)
+                .filter(|pitch| range.contains(*pitch))
+        };
+
+        let voice = |tones: [u16; 3]| -> Option<[Pitch; 4]> {
+            let floor = (u16::from(bass.semitones()) + 1).max(TENOR_RANGE.low().semitones().into());
+            let mut tenor = lowest_above(tones[0], floor);
+            let mut alto = lowest_above(tones[1], tenor + 1);
+            let mut soprano = lowest_above(tones[2], alto + 1);
+            while soprano < u16::from(SOPRANO_RANGE.low().semitones()) {
+                tenor += octave;
+                alto += octave;
+                soprano += octave;
+            }
+
+            Some([
+                bass,
+                in_range(tenor, TENOR_RANGE)?,
+                in_range(alto, ALTO_RANGE)?,
+                in_range(soprano, SOPRANO_RANGE)?,
+            ])
+        };
+
+        ORDERINGS
+            .iter()
+            .find_map(|ordering| voice(ordering.map(|index| upper[index])))
+            .ok_or(ChordError::OutOfVoiceRange)
+    }
+
+    /// Lists the tensions a scale makes available over the chord.
+    ///
+    /// A tension is a ninth, eleventh or thirteenth above the root whose pitch class is in
+    /// the scale and not already a chord tone. Tensions a half step above a chord tone
+    /// clash with it and are left out as avoid notes, e.g. the eleventh over a major triad.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale the chord is played over
+    ///
+    /// # Returns
+    ///
+    /// The compound intervals of the available tensions above the root, in ascending order
+    pub fn tensions<Q: ScaleQuality, const N: usize>(
+        &self,
+        scale: &ScaleInPitches<Q, N>,
+    ) -> Vec<Interval> {
+        let root = PitchClass::from(self.root);
+        let scale = PitchClassSet::from(scale);
+        let chord: PitchClassSet = self.chord_tones().collect();
+
+        TENSION_SEMITONES
+            .iter()
+            .copied()
+            .filter(|semitones| {
+                let tension = root.transpose(*semitones);
+                let below = tension.transpose(SEMITONES_IN_OCTAVE - 1);
+                scale.contains(tension) && !chord.contains(tension) && !chord.contains(below)
+            })
+            .map(|semitones| Interval::new(semitones + SEMITONES_IN_OCTAVE))
+            .collect()
+    }
+
+    /// Returns the pitches of every inversion of the chord, starting with the root position.
+    ///
+    /// # Returns
+    ///
+    /// One pitch list per inversion, as returned by [`Chord::inversion`]
+    pub fn all_inversions(&self) -> Vec<Vec<Pitch>> {
+        (0..=self.intervals.len())
+            .filter_map(|n| self.inversion(n))
+            .collect()
+    }
+}
+
+/// Builds a chord one chord tone at a time.
+///
+/// The root defaults to C4 until [`ChordBuilder::root`] is called. For example, a C
+/// dominant seventh chord is built with
+/// `ChordBuilder::new().root(C4).add_third().add_fifth().add_seventh().build()`.
+#[derive(Debug, Clone, Default)]
+pub struct ChordBuilder {
+    root: Pitch,
+    intervals: Vec<Interval>,
+}
+
+impl ChordBuilder {
+    /// Creates a new builder with a C4 root and no chord tones above it.
+    ///
+    /// # Returns
+    ///
+    /// An empty ChordBuilder
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the root of the chord.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root pitch of the chord
+    ///
+    /// # Returns
+    ///
+    /// The builder with the new root
+    pub fn root(mut self, root: Pitch) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Adds a chord tone at an interval above the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval of the chord tone, measured from the root
+    ///
+    /// # Returns
+    ///
+    /// The builder with the chord tone added
+    pub fn add_interval(mut self, interval: Interval) -> Self {
+        self.intervals.push(interval);
+        self
+    }
+
+    /// Adds a major third above the root.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the third added
+    pub fn add_third(self) -> Self {
+        self.add_interval(MAJOR_THIRD)
+    }
+
+    /// Adds a perfect fifth above the root.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the fifth added
+    pub fn add_fifth(self) -> Self {
+        self.add_interval(PERFECT_FIFTH)
+    }
+
+    /// Adds a minor seventh above the root, as found in a dominant seventh chord.
+    ///
+    /// # Returns
+    ///
+    /// The builder with the seventh added
+    pub fn add_seventh(self) -> Self {
+        self.add_interval(MINOR_SEVENTH)
+    }
+
+    /// Builds the chord from the root and the chord tones added so far.
+    ///
+    /// # Returns
+    ///
+    /// The chord, with its chord tones in the order they were added
+    pub fn build(self) -> Chord {
+        Chord::new(self.root, self.intervals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::*;
+
+    fn c_major() -> Chord {
+        Chord::new(C4, vec![MAJOR_THIRD, PERFECT_FIFTH])
+    }
+
+    #[test]
+    fn test_tensions_of_major_triad() {
+        let c_major_scale = crate::scales::major_scale(C4);
+        assert_eq!(
+            c_major().tensions(&c_major_scale),
+            vec![Interval::new(14), Interval::new(21)]
+        );
+    }
+
+    #[test]
+    fn test_tensions_of_dominant_seventh() {
+        let g7 = Chord::new(G4, vec![MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH]);
+        let c_major_scale = crate::scales::major_scale(C4);
+        assert_eq!(
+            g7.tensions(&c_major_scale),
+            vec![Interval::new(14), Interval::new(21)]
+        );
+    }
+
+    #[test]
+    fn test_distribute_satb_major_seventh() {
+        let c_major_seventh = Chord::new(C4, vec![MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SEVENTH]);
+        let voices = c_major_seventh.distribute_satb(C3).unwrap();
+
+        assert!(voices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(BASS_RANGE.contains(voices[0]));
+        assert!(TENOR_RANGE.contains(voices[1]));
+        assert!(ALTO_RANGE.contains(voices[2]));
+        assert!(SOPRANO_RANGE.contains(voices[3]));
+        assert_eq!(voices, [C3, E4, G4, B4]);
+    }
+
+    #[test]
+    fn test_distribute_satb_doubles_root_of_triad() {
+        assert_eq!(c_major().distribute_satb(C3), Ok([C3, E3, G3, C4]));
+        assert_eq!(c_major().distribute_satb(E3), Ok([E3, C4, G4, C5]));
+    }
+
+    #[test]
+    fn test_distribute_satb_out_of_voice_range() {
+        assert_eq!(
+            c_major().distribute_satb(C5),
+            Err(ChordError::OutOfVoiceRange)
+        );
+        assert_eq!(
+            c_major().distribute_satb(G9),
+            Err(ChordError::OutOfVoiceRange)
+        );
+        assert_eq!(
+            c_major().distribute_satb(C2),
+            Err(ChordError::OutOfVoiceRange)
+        );
+    }
+
+    #[test]
+    fn test_distribute_satb_omits_fifth_over_foreign_bass() {
+        let c_dominant_seventh = Chord::new(C4, vec![MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH]);
+        let voices = c_dominant_seventh.distribute_satb(D3).unwrap();
+
+        assert_eq!(voices[0], D3);
+        let upper: Vec<u8> = voices[1..]
+            .iter()
+            .map(|pitch| pitch.semitones() % 12)
+            .collect();
+        assert!(upper.contains(&0));
+        assert!(upper.contains(&4));
+        assert!(upper.contains(&10));
+        assert!(!upper.contains(&7));
+    }
+
+    #[test]
+    fn test_distribute_satb_too_many_tones() {
+        let c_ninth = Chord::new(
+            C4,
+            vec![MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH, Interval::new(14)],
+        );
+        assert_eq!(c_ninth.distribute_satb(F3), Err(ChordError::TooManyTones));
+    }
+
+    #[test]
+    fn test_chord_creation() {
+        let chord = c_major();
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.intervals(), &[MAJOR_THIRD, PERFECT_FIFTH]);
+    }
+
+    #[test]
+    fn test_chord_pitches() {
+        assert_eq!(c_major().pitches(), vec![C4, E4, G4]);
+    }
+
+    #[test]
+    fn test_chord_inversion() {
+        let chord = c_major();
+        assert_eq!(chord.inversion(0), Some(vec![C4, E4, G4]));
+        assert_eq!(chord.inversion(1), Some(vec![E4, G4, C5]));
+        assert_eq!(chord.inversion(2), Some(vec![G4, C5, E5]));
+        assert_eq!(chord.inversion(3), None);
+    }
+
+    #[test]
+    fn test_chord_all_inversions() {
+        assert_eq!(
+            c_major().all_inversions(),
+            vec![vec![C4, E4, G4], vec![E4, G4, C5], vec![G4, C5, E5]]
+        );
+    }
+
+    #[test]
+    fn test_chord_builder_dominant_ninth() {
+        let chord = ChordBuilder::new()
+            .root(C4)
+            .add_third()
+            .add_fifth()
+            .add_seventh()
+            .add_interval(Interval::new(14))
+            .build();
+        assert_eq!(chord.pitches(), vec![C4, E4, G4, BFLAT4, D5]);
+    }
+
+    #[test]
+    fn test_chord_builder_default_root() {
+        let chord = ChordBuilder::new().add_third().add_fifth().build();
+        assert_eq!(chord, c_major());
+    }
+
+    #[test]
+    fn test_chord_transpose() {
+        let chord = c_major().transpose(PERFECT_FOURTH).unwrap();
+        assert_eq!(chord, Chord::new(F4, vec![MAJOR_THIRD, PERFECT_FIFTH]));
+        assert_eq!(chord.pitches(), vec![F4, A4, C5]);
+    }
+
+    #[test]
+    fn test_chord_transpose_slash_chord() {
+        let chord = c_major().with_bass(G3).transpose(MAJOR_SECOND).unwrap();
+        assert_eq!(chord.pitches(), vec![A3, D4, FSHARP4, A4]);
+    }
+
+    #[test]
+    fn test_chord_transpose_out_of_range() {
+        let chord = Chord::new(C9, vec![MAJOR_THIRD, PERFECT_FIFTH]);
+        assert_eq!(chord.transpose(MINOR_SECOND), Err(ChordError::OutOfRange));
+    }
+
+    #[test]
+    fn test_chord_to_interval_scale() {
+        let chord = ChordBuilder::new()
+            .root(G4)
+            .add_third()
+            .add_fifth()
+            .add_seventh()
+            .build();
+        assert_eq!(
+            chord.to_interval_scale(),
+            vec![MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH]
+        );
+    }
+
+    #[test]
+    fn test_chord_with_bass() {
+        let chord = c_major().with_bass(G3);
+        assert_eq!(chord.bass(), Some(G3));
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.pitches(), vec![G3, C4, E4, G4]);
+        assert_eq!(c_major().bass(), None);
+    }
+
+    #[test]
+    fn test_chord_with_bass_above_root() {
+        assert_eq!(c_major().with_bass(C5).pitches(), vec![C3, C4, E4, G4]);
+        assert_eq!(c_major().with_bass(E6).bass(), Some(E3));
+        assert_eq!(c_major().with_bass(C4).bass(), Some(C3));
+        assert_eq!(
+            Chord::new(C, vec![MAJOR_THIRD]).with_bass(E4).bass(),
+            Some(E)
+        );
+    }
+
+    #[test]
+    fn test_chord_with_non_chord_tone_bass() {
+        let chord = c_major().with_bass(D3);
+        assert_eq!(chord.pitches()[0], D3);
+        assert_eq!(chord.inversion(1), Some(vec![E4, G4, C5]));
+    }
+
+    #[test]
+    fn test_triad_quality_major() {
+        assert_eq!(triad_quality(&[C4, E4, G4]), Some(TriadQuality::Major));
+    }
+
+    #[test]
+    fn test_triad_quality_minor() {
+        assert_eq!(triad_quality(&[A4, C5, E5]), Some(TriadQuality::Minor));
+    }
+
+    #[test]
+    fn test_triad_quality_diminished() {
+        assert_eq!(triad_quality(&[B4, D5, F5]), Some(TriadQuality::Diminished));
+    }
+
+    #[test]
+    fn test_triad_quality_augmented() {
+        assert_eq!(
+            triad_quality(&[C4, E4, GSHARP4]),
+            Some(TriadQuality::Augmented)
+        );
+    }
+
+    #[test]
+    fn test_triad_quality_unrecognized() {
+        assert_eq!(triad_quality(&[C4, D4, G4]), None);
+        assert_eq!(triad_quality(&[G4, E4, C4]), None);
+    }
+
+    #[test]
+    fn test_chord_inversion_wider_than_octave() {
+        let chord = Chord::new(C4, vec![MAJOR_THIRD, PERFECT_FIFTH, Interval::new(14)]);
+        assert_eq!(chord.inversion(1), Some(vec![E4, G4, D5, C6]));
+    }
+}
@@ -0,0 +1,158 @@
+//! Circle of fifths module
+//!
+//! The circle of fifths orders the twelve keys so that neighboring keys are a perfect
+//! fifth apart and differ by a single accidental in their key signatures. This module
+//! provides functions for choosing key spellings from a key's position on the circle and
+//! for measuring how far apart two keys are.
+
+use super::{PitchClass, SEMITONES_IN_OCTAVE};
+
+/// Number of semitones in a perfect fifth, the distance between neighboring keys.
+const FIFTH: u8 = 7;
+
+/// Number of semitones between a minor key and its relative major.
+const MINOR_TO_RELATIVE_MAJOR: u8 = 3;
+
+/// Names of the twelve pitch classes, spelled with sharps.
+const SHARP_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Names of the twelve pitch classes, spelled with flats.
+const FLAT_NAMES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+];
+
+/// Returns the number of fifths above C of a major key, which is its number of sharps.
+fn fifths_above_c(pitch_class: PitchClass) -> u8 {
+    pitch_class.semitones() * FIFTH % SEMITONES_IN_OCTAVE
+}
+
+/// Returns the keys next to a key on the circle of fifths.
+///
+/// # Arguments
+///
+/// * `pitch_class` - The pitch class of the key's root
+/// * `is_major` - `true` for a major key, `false` for a minor key
+///
+/// # Returns
+///
+/// The dominant, subdominant and relative keys, in that order, as (root, is_major) pairs
+pub fn neighboring_keys(pitch_class: PitchClass, is_major: bool) -> [(PitchClass, bool); 3] {
+    let relative = if is_major {
+        pitch_class.transpose(SEMITONES_IN_OCTAVE - MINOR_TO_RELATIVE_MAJOR)
+    } else {
+        pitch_class.transpose(MINOR_TO_RELATIVE_MAJOR)
+    };
+
+    [
+        (pitch_class.transpose(FIFTH), is_major),
+        (pitch_class.transpose(SEMITONES_IN_OCTAVE - FIFTH), is_major),
+        (relative, !is_major),
+    ]
+}
+
+/// Returns the distance between two keys on the circle of fifths.
+///
+/// The distance is the smallest number of fifths, up or down, that leads from one key to
+/// the other, so closely related keys such as C and G are one step apart.
+///
+/// # Arguments
+///
+/// * `a` - The pitch class of the first key's root
+/// * `b` - The pitch class of the second key's root
+///
+/// # Returns
+///
+/// The number of fifth steps between the keys, from 0 to 6
+pub fn key_distance(a: PitchClass, b: PitchClass) -> u8 {
+    let fifths =
+        (fifths_above_c(b) + SEMITONES_IN_OCTAVE - fifths_above_c(a)) % SEMITONES_IN_OCTAVE;
+    fifths.min(SEMITONES_IN_OCTAVE - fifths)
+}
+
+/// Suggests how to spell the root of a key, with sharps or with flats.
+///
+/// Every key can be reached by going up the circle of fifths (adding sharps) or down it
+/// (adding flats). The spelling with fewer accidentals in its key signature wins. When
+/// both need six accidentals, as for F♯/G♭ major and D♯/E♭ minor, sharps are preferred.
+///
+/// # Arguments
+///
+/// * `pitch_class` - The pitch class of the key's root
+/// * `is_major` - `true` for a major key, `false` for a minor key
+///
+/// # Returns
+///
+/// The name of the root, e.g. `"F#"` for a major key on pitch class 6 or `"Bb"` for a
+/// major key on pitch class 10
+pub fn suggest_root_spelling(pitch_class: PitchClass, is_major: bool) -> String {
+    let major = if is_major {
+        pitch_class
+    } else {
+        pitch_class.transpose(MINOR_TO_RELATIVE_MAJOR)
+    };
+
+    let sharps = fifths_above_c(major);
+    let flats = (SEMITONES_IN_OCTAVE - sharps) % SEMITONES_IN_OCTAVE;
+    let names = if sharps <= flats {
+        SHARP_NAMES
+    } else {
+        FLAT_NAMES
+    };
+
+    names[pitch_class.semitones() as usize].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighboring_keys() {
+        assert_eq!(
+            neighboring_keys(PitchClass::new(0), true),
+            [
+                (PitchClass::new(7), true),
+                (PitchClass::new(5), true),
+                (PitchClass::new(9), false)
+            ]
+        );
+        assert_eq!(
+            neighboring_keys(PitchClass::new(9), false)[2],
+            (PitchClass::new(0), true)
+        );
+    }
+
+    #[test]
+    fn test_key_distance() {
+        let c = PitchClass::new(0);
+        assert_eq!(key_distance(c, c), 0);
+        assert_eq!(key_distance(c, PitchClass::new(7)), 1);
+        assert_eq!(key_distance(c, PitchClass::new(5)), 1);
+        assert_eq!(key_distance(c, PitchClass::new(2)), 2);
+        assert_eq!(key_distance(c, PitchClass::new(6)), 6);
+        assert_eq!(key_distance(PitchClass::new(10), PitchClass::new(4)), 6);
+    }
+
+    #[test]
+    fn test_major_tie_prefers_sharps() {
+        assert_eq!(suggest_root_spelling(PitchClass::new(6), true), "F#");
+    }
+
+    #[test]
+    fn test_major_spellings() {
+        assert_eq!(suggest_root_spelling(PitchClass::new(0), true), "C");
+        assert_eq!(suggest_root_spelling(PitchClass::new(1), true), "Db");
+        assert_eq!(suggest_root_spelling(PitchClass::new(10), true), "Bb");
+        assert_eq!(suggest_root_spelling(PitchClass::new(11), true), "B");
+    }
+
+    #[test]
+    fn test_minor_spellings() {
+        assert_eq!(suggest_root_spelling(PitchClass::new(1), false), "C#");
+        assert_eq!(suggest_root_spelling(PitchClass::new(3), false), "D#");
+        assert_eq!(suggest_root_spelling(PitchClass::new(10), false), "Bb");
+        assert_eq!(suggest_root_spelling(PitchClass::new(8), false), "G#");
+    }
+}
@@ -1,3 +1,4 @@
 pub use crate::core::interval::constants::*;
+pub use crate::core::note::constants::*;
 pub use crate::core::pitch::constants::*;
 pub use crate::core::step::constants::*;
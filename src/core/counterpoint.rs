@@ -0,0 +1,147 @@
+//! Counterpoint module for checking voice-leading between melodic lines
+//!
+//! A voice is a sequence of pitches sounding one after another. This module provides
+//! functions that examine two voices moving together and detect classic voice-leading
+//! errors.
+
+use std::cmp::Ordering;
+
+use super::{Pitch, SEMITONES_IN_OCTAVE};
+
+use crate::PERFECT_FIFTH;
+
+/// Returns true if the harmonic interval between two pitches is a perfect fifth or a compound perfect fifth.
+#[inline]
+fn is_perfect_fifth(a: Pitch, b: Pitch) -> bool {
+    a.semitones().abs_diff(b.semitones()) % SEMITONES_IN_OCTAVE == PERFECT_FIFTH.semitones()
+}
+
+/// Checks whether two voices move in parallel perfect fifths.
+///
+/// Parallel fifths occur when the two voices form a perfect fifth (or a compound
+/// perfect fifth), then both move in the same direction and form a perfect fifth
+/// again. Only the common length of the two voices is examined.
+///
+/// # Arguments
+///
+/// * `voice_a` - The pitches of the first voice
+/// * `voice_b` - The pitches of the second voice
+///
+/// # Returns
+///
+/// `true` if any pair of consecutive positions forms parallel fifths, `false` otherwise
+///
+/// # Examples
+///
+/// ```rust
+/// use no_surprises::prelude::*;
+///
+/// let upper = [G4, A4];
+/// let lower = [C4, D4];
+/// assert!(has_parallel_fifths(&upper, &lower));
+/// ```
+pub fn has_parallel_fifths(voice_a: &[Pitch], voice_b: &[Pitch]) -> bool {
+    voice_a.windows(2).zip(voice_b.windows(2)).any(|(a, b)| {
+        let direction = a[1].cmp(&a[0]);
+
+        direction != Ordering::Equal
+            && direction == b[1].cmp(&b[0])
+            && is_perfect_fifth(a[0], b[0])
+            && is_perfect_fifth(a[1], b[1])
+    })
+}
+
+/// Computes the voice-leading distance between two chords.
+///
+/// The voices of the two chords are matched by position, so the first pitch of
+/// `from` moves to the first pitch of `to`, and so on. The distance is the total
+/// number of semitones moved by all voices, in either direction. Only the common
+/// length of the two chords is examined.
+///
+/// # Arguments
+///
+/// * `from` - The pitches of the starting chord, one per voice
+/// * `to` - The pitches of the destination chord, one per voice
+///
+/// # Returns
+///
+/// The sum of the absolute semitone movements of every voice
+///
+/// # Examples
+///
+/// ```rust
+/// use no_surprises::prelude::*;
+///
+/// // C major to F major (second inversion): C stays, E moves to F, G moves to A.
+/// assert_eq!(voice_leading_distance(&[C4, E4, G4], &[C4, F4, A4]), 3);
+/// ```
+pub fn voice_leading_distance(from: &[Pitch], to: &[Pitch]) -> u32 {
+    from.iter()
+        .zip(to)
+        .map(|(a, b)| u32::from(a.semitones().abs_diff(b.semitones())))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::*;
+
+    #[test]
+    fn test_parallel_fifths() {
+        let soprano = [G4, A4, B4];
+        let bass = [C4, D4, E4];
+        assert!(has_parallel_fifths(&soprano, &bass));
+    }
+
+    #[test]
+    fn test_parallel_compound_fifths() {
+        let soprano = [G5, F5];
+        let bass = [C4, BFLAT3];
+        assert!(has_parallel_fifths(&soprano, &bass));
+    }
+
+    #[test]
+    fn test_contrary_motion_is_not_parallel() {
+        let soprano = [G4, F4];
+        let bass = [C4, D4];
+        assert!(!has_parallel_fifths(&soprano, &bass));
+
+        let soprano = [G4, A4];
+        let bass = [C4, D3];
+        assert!(!has_parallel_fifths(&soprano, &bass));
+    }
+
+    #[test]
+    fn test_repeated_fifth_is_not_parallel() {
+        let soprano = [G4, G4];
+        let bass = [C4, C4];
+        assert!(!has_parallel_fifths(&soprano, &bass));
+    }
+
+    #[test]
+    fn test_voice_leading_distance() {
+        let c_major = [C4, E4, G4];
+        let close_f_major = [F4, A4, C5];
+        let leaping_f_major = [F2, A5, C6];
+
+        let close = voice_leading_distance(&c_major, &close_f_major);
+        let leaping = voice_leading_distance(&c_major, &leaping_f_major);
+
+        assert_eq!(close, 15);
+        assert_eq!(leaping, 19 + 17 + 17);
+        assert!(close < leaping);
+    }
+
+    #[test]
+    fn test_voice_leading_distance_same_chord() {
+        assert_eq!(voice_leading_distance(&[C4, E4, G4], &[C4, E4, G4]), 0);
+        assert_eq!(voice_leading_distance(&[], &[]), 0);
+    }
+
+    #[test]
+    fn test_short_voices() {
+        assert!(!has_parallel_fifths(&[G4], &[C4]));
+        assert!(!has_parallel_fifths(&[], &[]));
+    }
+}
@@ -4,11 +4,12 @@
 //! This module provides functionality for working with intervals, including conversion
 //! to and from steps, and operations on collections of intervals.
 
+use std::cmp::Ordering;
 use std::ops::{Add, Sub};
 
 use crate::PERFECT_UNISON;
 
-use super::{IntoPitches, IntoSteps, Pitch, Step};
+use super::{IntoPitches, IntoSteps, Pitch, SEMITONES_IN_OCTAVE, Step};
 
 /// Represents a musical interval, which is the distance between two pitches in semitones.
 ///
@@ -42,6 +43,103 @@ impl Interval {
     pub const fn semitones(&self) -> u8 {
         self.0
     }
+
+    /// Returns true if this is a perfect interval (unison, fourth, fifth or octave).
+    ///
+    /// Compound intervals are classified like their simple counterparts, so an eleventh
+    /// is perfect like a fourth. The tritone is neither perfect, major nor minor.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the interval is perfect, `false` otherwise
+    #[inline]
+    pub const fn is_perfect(&self) -> bool {
+        matches!(self.0 % SEMITONES_IN_OCTAVE, 0 | 5 | 7)
+    }
+
+    /// Returns true if this is a major interval (second, third, sixth or seventh).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the interval is major, `false` otherwise
+    #[inline]
+    pub const fn is_major(&self) -> bool {
+        matches!(self.0 % SEMITONES_IN_OCTAVE, 2 | 4 | 9 | 11)
+    }
+
+    /// Returns true if this is a minor interval (second, third, sixth or seventh).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the interval is minor, `false` otherwise
+    #[inline]
+    pub const fn is_minor(&self) -> bool {
+        matches!(self.0 % SEMITONES_IN_OCTAVE, 1 | 3 | 8 | 10)
+    }
+
+    /// Adds a step to this interval, checking for overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - The step to add
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the sum of the semitones, or `None` if it exceeds 255 semitones
+    #[inline]
+    pub const fn checked_add(self, step: Step) -> Option<Interval> {
+        match self.0.checked_add(step.semitones()) {
+            Some(semitones) => Some(Self(semitones)),
+            None => None,
+        }
+    }
+
+    /// Ranks the interval by consonance, from 0 for the most consonant.
+    ///
+    /// The ranking follows the conventional order: unison and octave, fifth, fourth, major
+    /// and minor thirds and sixths, seconds and sevenths, and finally the tritone as the
+    /// most dissonant. Compound intervals rank like their simple counterparts.
+    ///
+    /// # Returns
+    ///
+    /// The consonance rank, from 0 (unison or octave) to 11 (tritone)
+    #[inline]
+    pub const fn consonance_rank(&self) -> u8 {
+        match self.0 % SEMITONES_IN_OCTAVE {
+            0 => 0,
+            7 => 1,
+            5 => 2,
+            4 => 3,
+            9 => 4,
+            3 => 5,
+            8 => 6,
+            2 => 7,
+            10 => 8,
+            1 => 9,
+            11 => 10,
+            _ => 11,
+        }
+    }
+
+    /// Compares two named intervals by size, breaking ties by name.
+    ///
+    /// The derived `Ord` compares semitones only, so enharmonic spellings such as
+    /// `("Augmented Fourth", AUGMENTED_FOURTH)` and `("Diminished Fifth", DIMINISHED_FIFTH)`
+    /// compare equal and keep whatever order they came in. Comparing the names as well
+    /// gives the same sorted order regardless of the input order. Use it with
+    /// `sort_by(Interval::cmp_by_name)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first interval and its name
+    /// * `b` - The second interval and its name
+    ///
+    /// # Returns
+    ///
+    /// The ordering by semitones, then alphabetically by name
+    pub fn cmp_by_name(a: &(Interval, &str), b: &(Interval, &str)) -> Ordering {
+        a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1))
+    }
 }
 
 impl Default for Interval {
@@ -68,11 +166,47 @@ impl From<Step> for Interval {
     }
 }
 
+impl PartialEq<Step> for Interval {
+    /// Compares an interval and a step by their number of semitones.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The interval to compare
+    /// * `other` - The step to compare with
+    ///
+    /// # Returns
+    ///
+    /// `true` if both have the same number of semitones
+    #[inline]
+    fn eq(&self, other: &Step) -> bool {
+        self.0 == other.semitones()
+    }
+}
+
+impl PartialOrd<Step> for Interval {
+    /// Orders an interval and a step by their number of semitones.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The interval to compare
+    /// * `other` - The step to compare with
+    ///
+    /// # Returns
+    ///
+    /// The ordering of the interval's semitones relative to the step's semitones
+    #[inline]
+    fn partial_cmp(&self, other: &Step) -> Option<Ordering> {
+        Some(self.0.cmp(&other.semitones()))
+    }
+}
+
 impl Add<Step> for Interval {
     type Output = Self;
 
     /// Adds a step to an interval.
     ///
+    /// Use [`Interval::checked_add`] when the sum may not fit in a `u8`.
+    ///
     /// # Arguments
     ///
     /// * `self` - The interval to add to
@@ -81,6 +215,11 @@ impl Add<Step> for Interval {
     /// # Returns
     ///
     /// A new interval with the sum of the semitones
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the sum exceeds 255 semitones; release builds wrap
+    /// around instead
     #[inline]
     fn add(self, step: Step) -> Self::Output {
         Self(self.0 + step.semitones())
@@ -212,6 +351,47 @@ impl<const N: usize> IntoPitches for [Interval; N] {
     }
 }
 
+/// Transposes every interval of a sequence up by the same step.
+///
+/// # Arguments
+///
+/// * `intervals` - The intervals to transpose
+/// * `by` - The step added to every interval
+///
+/// # Returns
+///
+/// The transposed intervals, saturating at the largest representable interval
+pub fn transpose_intervals<const N: usize>(intervals: [Interval; N], by: Step) -> [Interval; N] {
+    intervals.map(|interval| Interval::new(interval.semitones().saturating_add(by.semitones())))
+}
+
+/// Converts cumulative intervals into steps, measuring the first step from unison.
+///
+/// Intervals are measured from the tonic, so the first step is the first interval itself
+/// and every later step is the difference between consecutive intervals. This gives the
+/// same result as `IntoSteps::into_steps`, but names the unison reference explicitly,
+/// e.g. `[MAJOR_SECOND, PERFECT_FOURTH]` becomes a whole step followed by three semitones.
+///
+/// # Arguments
+///
+/// * `intervals` - The intervals above the tonic, in ascending order
+///
+/// # Returns
+///
+/// The steps between consecutive degrees, starting from the tonic
+///
+/// # Panics
+///
+/// Panics if the intervals are not in ascending order
+pub fn into_relative_steps<const N: usize>(intervals: [Interval; N]) -> [Step; N] {
+    let mut previous = PERFECT_UNISON;
+    intervals.map(|interval| {
+        let step = interval - previous;
+        previous = interval;
+        step
+    })
+}
+
 /// Constants for common musical intervals.
 pub(crate) mod constants {
     use super::Interval;
@@ -244,6 +424,29 @@ pub(crate) mod constants {
     pub const MAJOR_SEVENTH: Interval = Interval::new(11);
     /// Perfect octave (12 semitones)
     pub const PERFECT_OCTAVE: Interval = Interval::new(12);
+
+    /// Names of the intervals up to the octave, indexed by number of semitones
+    pub const INTERVAL_NAMES: [&str; 13] = [
+        "Perfect Unison",
+        "Minor Second",
+        "Major Second",
+        "Minor Third",
+        "Major Third",
+        "Perfect Fourth",
+        "Tritone",
+        "Perfect Fifth",
+        "Minor Sixth",
+        "Major Sixth",
+        "Minor Seventh",
+        "Major Seventh",
+        "Perfect Octave",
+    ];
+
+    /// Octave complement of each interval up to the octave, indexed by number of semitones
+    ///
+    /// An interval and its complement add up to an octave, e.g. a major third (4)
+    /// inverts to a minor sixth (8).
+    pub const INTERVAL_INVERSIONS: [u8; 13] = [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
 }
 
 #[cfg(test)]
@@ -251,6 +454,137 @@ mod tests {
     use super::constants::*;
     use super::*;
 
+    #[test]
+    fn test_checked_add() {
+        assert_eq!(MAJOR_THIRD.checked_add(Step::new(3)), Some(PERFECT_FIFTH));
+        assert_eq!(
+            Interval::new(250).checked_add(Step::new(5)),
+            Some(Interval::new(255))
+        );
+        assert_eq!(Interval::new(250).checked_add(Step::new(6)), None);
+    }
+
+    #[test]
+    fn test_consonance_rank() {
+        assert!(PERFECT_OCTAVE.consonance_rank() < MAJOR_SEVENTH.consonance_rank());
+        assert_eq!(PERFECT_UNISON.consonance_rank(), 0);
+        assert!(PERFECT_FIFTH.consonance_rank() < PERFECT_FOURTH.consonance_rank());
+        assert!(MAJOR_THIRD.consonance_rank() < MAJOR_SECOND.consonance_rank());
+        assert_eq!(
+            Interval::new(19).consonance_rank(),
+            PERFECT_FIFTH.consonance_rank()
+        );
+
+        let tritone = AUGMENTED_FOURTH.consonance_rank();
+        assert!((0..=12).all(|semitones| Interval::new(semitones).consonance_rank() <= tritone));
+        assert_eq!(
+            (0..=11)
+                .filter(|semitones| Interval::new(*semitones).consonance_rank() == tritone)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_interval_classification() {
+        assert!(PERFECT_FIFTH.is_perfect());
+        assert!(PERFECT_OCTAVE.is_perfect());
+        assert!(MAJOR_THIRD.is_major());
+        assert!(!MAJOR_THIRD.is_minor());
+        assert!(MINOR_SEVENTH.is_minor());
+        assert!(Interval::new(14).is_major());
+    }
+
+    #[test]
+    fn test_tritone_is_unclassified() {
+        assert!(!AUGMENTED_FOURTH.is_perfect());
+        assert!(!AUGMENTED_FOURTH.is_major());
+        assert!(!AUGMENTED_FOURTH.is_minor());
+    }
+
+    #[test]
+    fn test_cmp_by_name_orders_tritone_spellings() {
+        let mut intervals = vec![
+            (PERFECT_FIFTH, "Perfect Fifth"),
+            (DIMINISHED_FIFTH, "Diminished Fifth"),
+            (MINOR_THIRD, "Minor Third"),
+            (AUGMENTED_FOURTH, "Augmented Fourth"),
+        ];
+        intervals.sort_by(Interval::cmp_by_name);
+        assert_eq!(
+            intervals,
+            vec![
+                (MINOR_THIRD, "Minor Third"),
+                (AUGMENTED_FOURTH, "Augmented Fourth"),
+                (DIMINISHED_FIFTH, "Diminished Fifth"),
+                (PERFECT_FIFTH, "Perfect Fifth"),
+            ]
+        );
+
+        let mut reversed = intervals.clone();
+        reversed.reverse();
+        reversed.sort_by(Interval::cmp_by_name);
+        assert_eq!(reversed, intervals);
+    }
+
+    #[test]
+    fn test_transpose_intervals() {
+        let major = [
+            MAJOR_SECOND,
+            MAJOR_THIRD,
+            PERFECT_FOURTH,
+            PERFECT_FIFTH,
+            MAJOR_SIXTH,
+            MAJOR_SEVENTH,
+            PERFECT_OCTAVE,
+        ];
+        assert_eq!(
+            transpose_intervals(major, Step::new(2)),
+            [
+                MAJOR_THIRD,
+                AUGMENTED_FOURTH,
+                PERFECT_FIFTH,
+                MAJOR_SIXTH,
+                MAJOR_SEVENTH,
+                Interval::new(13),
+                Interval::new(14),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transpose_intervals_saturates() {
+        assert_eq!(
+            transpose_intervals([Interval::new(250)], Step::new(10)),
+            [Interval::new(u8::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_interval_inversions() {
+        assert_eq!(INTERVAL_INVERSIONS[4], 8);
+        assert_eq!(
+            INTERVAL_INVERSIONS[AUGMENTED_FOURTH.semitones() as usize],
+            6
+        );
+        assert!(
+            INTERVAL_INVERSIONS
+                .iter()
+                .enumerate()
+                .all(|(semitones, inversion)| semitones as u8 + inversion == 12)
+        );
+    }
+
+    #[test]
+    fn test_interval_names() {
+        assert_eq!(INTERVAL_NAMES[7], "Perfect Fifth");
+        assert_eq!(
+            INTERVAL_NAMES[MAJOR_THIRD.semitones() as usize],
+            "Major Third"
+        );
+        assert_eq!(INTERVAL_NAMES[12], "Perfect Octave");
+    }
+
     #[test]
     fn test_interval_creation() {
         let interval = Interval::new(4);
@@ -303,6 +637,37 @@ mod tests {
         assert_eq!(interval1, Interval::new(4));
     }
 
+    #[test]
+    fn test_interval_step_equality() {
+        use crate::core::constants::{HALF, WHOLE};
+
+        assert!(MAJOR_SECOND == WHOLE);
+        assert!(MINOR_SECOND == HALF);
+        assert!(MAJOR_THIRD != WHOLE);
+    }
+
+    #[test]
+    fn test_interval_step_comparison() {
+        use crate::core::constants::{WHOLE, WHOLE_AND_HALF};
+
+        assert!(MAJOR_THIRD > WHOLE);
+        assert!(MINOR_SECOND < WHOLE);
+        assert!(MINOR_THIRD >= WHOLE_AND_HALF);
+        assert!(MINOR_THIRD <= WHOLE_AND_HALF);
+    }
+
+    #[test]
+    fn test_into_relative_steps() {
+        let intervals = [MAJOR_SECOND, PERFECT_FOURTH];
+
+        let relative = into_relative_steps(intervals);
+        assert_eq!(relative, [Step::new(2), Step::new(3)]);
+        assert_eq!(relative[0], Step::from(MAJOR_SECOND));
+
+        let steps: [Step; 2] = intervals.into_steps();
+        assert_eq!(steps, relative);
+    }
+
     #[test]
     fn test_into_steps() {
         let intervals = [Interval::new(2), Interval::new(5), Interval::new(9)];
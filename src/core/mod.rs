@@ -2,14 +2,22 @@
 //!
 //! This module provides the fundamental types and traits for working with musical concepts:
 //! - `Pitch`: Represents a specific musical note in the MIDI system
+//! - `PitchClass`: Represents a pitch without its octave
+//! - `PitchClassSet`: Represents an unordered set of pitch classes
 //! - `Interval`: Represents the distance between two pitches
 //! - `Step`: Represents the smallest unit of pitch movement
+//! - `Chord`: Represents a root pitch and the intervals of its chord tones
+//! - `Note`: Represents a pitch played for a `Duration`
 //!
 //! The module also provides traits for converting between these types:
 //! - `IntoIntervals`: Converts a sequence of elements into intervals
 //! - `IntoSteps`: Converts a sequence of elements into steps
 //! - `IntoPitches`: Converts a sequence of elements into pitches
 //!
+//! Twelve-tone row transformations (`retrograde`, `invert_row`, `ToneRow`) and voice-leading
+//! checks (`has_parallel_fifths`, `voice_leading_distance`) live alongside the core types,
+//! as do circle-of-fifths helpers such as `suggest_root_spelling`.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -30,17 +38,30 @@
 //! - Pitches: `C4`, `D4`, `E4`, etc.
 //! - Intervals: `PERFECT_UNISON`, `MAJOR_SECOND`, etc.
 //! - Steps: `HALF`, `WHOLE`, etc.
+//! - Durations: `QUARTER_NOTE`, `EIGHTH_NOTE`, etc.
 
 pub(crate) const SEMITONES_IN_OCTAVE: u8 = 12;
 
 pub mod constants;
 
+mod chord;
+mod circle;
+mod counterpoint;
 mod interval;
+mod note;
 mod pitch;
+mod pitch_class;
+mod serialism;
 mod step;
 
+pub use chord::*;
+pub use circle::*;
+pub use counterpoint::*;
 pub use interval::*;
+pub use note::*;
 pub use pitch::*;
+pub use pitch_class::*;
+pub use serialism::*;
 pub use step::*;
 
 /// Trait for converting a sequence of elements into intervals.
@@ -0,0 +1,122 @@
+//! Note module for handling pitches played for a duration
+//!
+//! A note pairs a pitch with a duration. Durations are measured in sixteenth notes, which
+//! is enough to time scale exercises and simple melodies without dealing with tuplets.
+
+use super::Pitch;
+
+/// Represents the length of a note, measured in sixteenth notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Duration(u16);
+
+impl Duration {
+    /// Creates a new duration with the given number of sixteenth notes.
+    ///
+    /// # Arguments
+    ///
+    /// * `sixteenths` - The length of the duration in sixteenth notes
+    ///
+    /// # Returns
+    ///
+    /// A new Duration with the specified length
+    #[inline]
+    pub const fn new(sixteenths: u16) -> Self {
+        Self(sixteenths)
+    }
+
+    /// Returns the length of this duration in sixteenth notes.
+    ///
+    /// # Returns
+    ///
+    /// The number of sixteenth notes as a u16
+    #[inline]
+    pub const fn sixteenths(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for Duration {
+    /// Returns a quarter note as the default value.
+    #[inline]
+    fn default() -> Self {
+        constants::QUARTER_NOTE
+    }
+}
+
+/// Represents a pitch played for a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Note {
+    pitch: Pitch,
+    duration: Duration,
+}
+
+impl Note {
+    /// Creates a new note.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch of the note
+    /// * `duration` - How long the note is played
+    ///
+    /// # Returns
+    ///
+    /// A new Note with the specified pitch and duration
+    #[inline]
+    pub const fn new(pitch: Pitch, duration: Duration) -> Self {
+        Self { pitch, duration }
+    }
+
+    /// Returns the pitch of this note.
+    ///
+    /// # Returns
+    ///
+    /// The pitch of the note
+    #[inline]
+    pub const fn pitch(&self) -> Pitch {
+        self.pitch
+    }
+
+    /// Returns the duration of this note.
+    ///
+    /// # Returns
+    ///
+    /// How long the note is played
+    #[inline]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Constants for common note durations.
+pub(crate) mod constants {
+    use super::Duration;
+    /// A whole note (16 sixteenth notes)
+    pub const WHOLE_NOTE: Duration = Duration::new(16);
+    /// A half note (8 sixteenth notes)
+    pub const HALF_NOTE: Duration = Duration::new(8);
+    /// A quarter note (4 sixteenth notes)
+    pub const QUARTER_NOTE: Duration = Duration::new(4);
+    /// An eighth note (2 sixteenth notes)
+    pub const EIGHTH_NOTE: Duration = Duration::new(2);
+    /// A sixteenth note
+    pub const SIXTEENTH_NOTE: Duration = Duration::new(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::constants::*;
+    use super::*;
+
+    #[test]
+    fn test_note_creation() {
+        let note = Note::new(Pitch::new(60), HALF_NOTE);
+        assert_eq!(note.pitch(), Pitch::new(60));
+        assert_eq!(note.duration().sixteenths(), 8);
+    }
+
+    #[test]
+    fn test_duration_default() {
+        assert_eq!(Duration::default(), QUARTER_NOTE);
+        assert!(EIGHTH_NOTE < WHOLE_NOTE);
+    }
+}
@@ -3,13 +3,41 @@
 //! A pitch represents a specific musical note in the MIDI system, where each pitch
 //! is assigned a unique number (0-127). This module provides functionality for working
 //! with pitches, including conversion to and from intervals and steps, and operations
-//! on collections of pitches.
+//! on collections of pitches. Pitches that deviate from equal temperament by a number of
+//! cents are represented by `MicroPitch`.
 
+use std::fmt;
 use std::ops::{Add, Sub};
 
 use crate::C4;
 
-use super::{Interval, IntoIntervals, IntoSteps, Step};
+use super::{Interval, IntoIntervals, IntoSteps, SEMITONES_IN_OCTAVE, Step};
+use constants::A4_FREQUENCY_HZ;
+
+/// MIDI note number of A4.
+const A4_SEMITONES: u8 = 69;
+
+/// Highest valid MIDI note number.
+const MAX_SEMITONES: u8 = 127;
+
+/// Note letters with the pitch class of their natural note.
+const LETTERS: [(char, u8); 7] = [
+    ('C', 0),
+    ('D', 2),
+    ('E', 4),
+    ('F', 5),
+    ('G', 7),
+    ('A', 9),
+    ('B', 11),
+];
+
+/// Accidentals by number of semitones, from most common to least common.
+const ACCIDENTALS: [(i16, &str); 5] = [(0, ""), (1, "#"), (-1, "b"), (2, "##"), (-2, "bb")];
+
+/// Names of the twelve pitch classes, spelled with sharps.
+const PITCH_CLASS_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
 
 /// Represents a musical pitch in the MIDI system.
 ///
@@ -33,6 +61,9 @@ impl Default for Pitch {
 }
 
 impl Pitch {
+    /// The highest valid pitch, MIDI note 127 (G9).
+    pub const MAX: Pitch = Pitch(MAX_SEMITONES);
+
     /// Creates a new pitch with the given MIDI note number.
     ///
     /// # Arguments
@@ -56,6 +87,319 @@ impl Pitch {
     pub const fn semitones(&self) -> u8 {
         self.0
     }
+
+    /// Moves the pitch up or down by a number of octaves.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of octaves to move by, negative to move down
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the shifted pitch, or `None` if it falls outside the MIDI range (0-127)
+    pub fn shift_octave(&self, n: i8) -> Option<Pitch> {
+        let semitones = i16::from(self.0) + i16::from(n) * i16::from(SEMITONES_IN_OCTAVE);
+        u8::try_from(semitones)
+            .ok()
+            .filter(|semitones| *semitones <= MAX_SEMITONES)
+            .map(Pitch::new)
+    }
+
+    /// Returns the spellings of this pitch with up to two accidentals.
+    ///
+    /// # Returns
+    ///
+    /// The note names with their octave numbers, natural spellings first, then single
+    /// sharps and flats, then double sharps and flats, e.g. `["C4", "B#3", "Dbb4"]`
+    /// for middle C
+    pub fn enharmonics(&self) -> Vec<String> {
+        let semitones = i16::from(self.0);
+        let octave = i16::from(SEMITONES_IN_OCTAVE);
+
+        ACCIDENTALS
+            .iter()
+            .flat_map(|(shift, accidental)| {
+                LETTERS.iter().filter_map(move |(letter, natural)| {
+                    let offset = semitones - i16::from(*natural) - shift;
+                    (offset.rem_euclid(octave) == 0)
+                        .then(|| format!("{}{}{}", letter, accidental, offset / octave - 1))
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the interval class between this pitch and another.
+    ///
+    /// The interval class is the smallest distance between the two pitch classes, so an
+    /// interval and its inversion (e.g. a minor second and a major seventh) share a class.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The pitch to measure to
+    ///
+    /// # Returns
+    ///
+    /// The interval class as a number of semitones (0-6)
+    #[inline]
+    pub const fn interval_class(&self, other: Pitch) -> u8 {
+        let distance = self.0.abs_diff(other.0) % SEMITONES_IN_OCTAVE;
+        if distance > SEMITONES_IN_OCTAVE - distance {
+            SEMITONES_IN_OCTAVE - distance
+        } else {
+            distance
+        }
+    }
+
+    /// Returns the equal-tempered frequency of this pitch.
+    ///
+    /// # Returns
+    ///
+    /// The frequency in Hz, with A4 tuned to 440 Hz
+    #[inline]
+    pub fn frequency_hz(&self) -> f64 {
+        self.frequency_hz_with(TuningStandard::default())
+    }
+
+    /// Returns the equal-tempered frequency of this pitch under a tuning standard.
+    ///
+    /// # Arguments
+    ///
+    /// * `tuning` - The tuning standard giving the frequency of A4
+    ///
+    /// # Returns
+    ///
+    /// The frequency in Hz
+    pub fn frequency_hz_with(&self, tuning: TuningStandard) -> f64 {
+        let semitones = f64::from(self.0) - f64::from(A4_SEMITONES);
+        tuning.a4_frequency_hz() * 2f64.powf(semitones / f64::from(SEMITONES_IN_OCTAVE))
+    }
+
+    /// Returns the equal-tempered pitch nearest to a frequency.
+    ///
+    /// # Arguments
+    ///
+    /// * `frequency` - The frequency in Hz, with A4 tuned to 440 Hz
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the nearest pitch, or `None` if it falls outside the MIDI range (0-127)
+    pub fn from_frequency_hz(frequency: f64) -> Option<Self> {
+        let semitones = f64::from(A4_SEMITONES)
+            + f64::from(SEMITONES_IN_OCTAVE) * (frequency / A4_FREQUENCY_HZ).log2();
+        let semitones = semitones.round();
+
+        (0.0..=f64::from(MAX_SEMITONES))
+            .contains(&semitones)
+            .then_some(Self(semitones as u8))
+    }
+}
+
+/// An inclusive range of pitches, such as the range of an instrument.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PitchRange {
+    low: Pitch,
+    high: Pitch,
+}
+
+impl PitchRange {
+    /// Creates a new pitch range.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The lowest pitch of the range, inclusive
+    /// * `high` - The highest pitch of the range, inclusive
+    ///
+    /// # Returns
+    ///
+    /// A new PitchRange
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is above `high`
+    #[inline]
+    pub const fn new(low: Pitch, high: Pitch) -> Self {
+        assert!(
+            low.0 <= high.0,
+            "The low pitch must not be above the high pitch"
+        );
+        Self { low, high }
+    }
+
+    /// Returns the lowest pitch of the range.
+    ///
+    /// # Returns
+    ///
+    /// The low bound, inclusive
+    #[inline]
+    pub const fn low(&self) -> Pitch {
+        self.low
+    }
+
+    /// Returns the highest pitch of the range.
+    ///
+    /// # Returns
+    ///
+    /// The high bound, inclusive
+    #[inline]
+    pub const fn high(&self) -> Pitch {
+        self.high
+    }
+
+    /// Returns true if a pitch lies within the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the pitch is between the low and high bounds, inclusive
+    #[inline]
+    pub const fn contains(&self, pitch: Pitch) -> bool {
+        self.low.0 <= pitch.0 && pitch.0 <= self.high.0
+    }
+
+    /// Moves a pitch into the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to clamp
+    ///
+    /// # Returns
+    ///
+    /// The pitch itself if it is in the range, otherwise the nearest bound
+    #[inline]
+    pub fn clamp(&self, pitch: Pitch) -> Pitch {
+        pitch.clamp(self.low, self.high)
+    }
+}
+
+/// The reference frequency of A4 that equal-tempered frequencies are computed from.
+///
+/// The default standard tunes A4 to 440 Hz, see [`A4_FREQUENCY_HZ`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TuningStandard {
+    a4_frequency_hz: f64,
+}
+
+impl TuningStandard {
+    /// Creates a new tuning standard from the frequency of A4.
+    ///
+    /// # Arguments
+    ///
+    /// * `a4_frequency_hz` - The frequency of A4 in Hz, e.g. 432.0
+    ///
+    /// # Returns
+    ///
+    /// A new TuningStandard
+    #[inline]
+    pub const fn new(a4_frequency_hz: f64) -> Self {
+        Self { a4_frequency_hz }
+    }
+
+    /// Returns the frequency of A4 under this tuning standard.
+    ///
+    /// # Returns
+    ///
+    /// The frequency of A4 in Hz
+    #[inline]
+    pub const fn a4_frequency_hz(&self) -> f64 {
+        self.a4_frequency_hz
+    }
+}
+
+impl Default for TuningStandard {
+    /// Returns the standard tuning with A4 at 440 Hz.
+    #[inline]
+    fn default() -> Self {
+        Self::new(A4_FREQUENCY_HZ)
+    }
+}
+
+impl fmt::Display for Pitch {
+    /// Formats the pitch as its sharp-spelled name followed by its octave number,
+    /// e.g. `C4` for middle C and `C#4` for the pitch a semitone above it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = PITCH_CLASS_NAMES[(self.0 % SEMITONES_IN_OCTAVE) as usize];
+        let octave = i16::from(self.0 / SEMITONES_IN_OCTAVE) - 1;
+        write!(f, "{}{}", name, octave)
+    }
+}
+
+/// Represents a pitch inflected by a number of cents away from equal temperament.
+///
+/// A cent is a hundredth of a semitone, so a quarter-tone sharp is an offset of 50 cents
+/// and a quarter-tone flat is an offset of -50 cents.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MicroPitch {
+    pitch: Pitch,
+    cents: i8,
+}
+
+impl MicroPitch {
+    /// Creates a new micro pitch from a pitch and a cents offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The equal-tempered pitch
+    /// * `cents` - The offset from the pitch in cents, positive for sharp and negative for flat
+    ///
+    /// # Returns
+    ///
+    /// A new MicroPitch with the specified pitch and offset
+    #[inline]
+    pub const fn new(pitch: Pitch, cents: i8) -> Self {
+        Self { pitch, cents }
+    }
+
+    /// Returns the equal-tempered pitch of this micro pitch.
+    ///
+    /// # Returns
+    ///
+    /// The pitch without its cents offset
+    #[inline]
+    pub const fn pitch(&self) -> Pitch {
+        self.pitch
+    }
+
+    /// Returns the cents offset of this micro pitch.
+    ///
+    /// # Returns
+    ///
+    /// The offset in cents, positive for sharp and negative for flat
+    #[inline]
+    pub const fn cents(&self) -> i8 {
+        self.cents
+    }
+}
+
+impl From<Pitch> for MicroPitch {
+    /// Converts a pitch into a micro pitch with no cents offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to convert
+    ///
+    /// # Returns
+    ///
+    /// A micro pitch with the same pitch and an offset of 0 cents
+    #[inline]
+    fn from(pitch: Pitch) -> Self {
+        Self::new(pitch, 0)
+    }
+}
+
+impl fmt::Display for MicroPitch {
+    /// Formats the micro pitch as its pitch name followed by the signed cents offset,
+    /// e.g. `C4+50c` for a quarter-tone sharp middle C. Pitches without an offset are
+    /// formatted like a plain pitch.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.cents == 0 {
+            write!(f, "{}", self.pitch)
+        } else {
+            write!(f, "{}{:+}c", self.pitch, self.cents)
+        }
+    }
 }
 
 impl Add<Step> for Pitch {
@@ -204,16 +548,96 @@ impl<const N: usize> IntoSteps for [Pitch; N] {
     }
 }
 
+/// Returns the intervals between consecutive pitches of a melody.
+///
+/// Each interval is the absolute distance between two consecutive pitches, so the
+/// melody may move up or down without underflowing.
+///
+/// # Arguments
+///
+/// * `pitches` - The pitches of the melody
+///
+/// # Returns
+///
+/// One interval per pair of consecutive pitches, or an empty vector for fewer than two pitches
+pub fn melodic_intervals(pitches: &[Pitch]) -> Vec<Interval> {
+    pitches
+        .windows(2)
+        .map(|pair| Interval::new(pair[0].0.abs_diff(pair[1].0)))
+        .collect()
+}
+
+/// Returns the largest leap between consecutive pitches of a melody.
+///
+/// # Arguments
+///
+/// * `pitches` - The pitches of the melody
+///
+/// # Returns
+///
+/// The largest absolute interval between consecutive pitches, or `None` for fewer than two pitches
+pub fn largest_leap(pitches: &[Pitch]) -> Option<Interval> {
+    melodic_intervals(pitches).into_iter().max()
+}
+
+/// Returns the harmonic series built on a fundamental pitch.
+///
+/// The `n`-th harmonic has `n` times the frequency of the fundamental. Each harmonic is
+/// paired with its nearest equal-tempered pitch, which shows where the natural harmonics
+/// diverge from equal temperament.
+///
+/// # Arguments
+///
+/// * `fundamental` - The pitch of the first harmonic
+/// * `count` - The number of harmonics to return
+///
+/// # Returns
+///
+/// Up to `count` pairs of (exact frequency in Hz, nearest pitch), starting with the
+/// fundamental. Harmonics whose nearest pitch is above the MIDI range are omitted.
+pub fn harmonic_series(fundamental: Pitch, count: usize) -> Vec<(f64, Pitch)> {
+    let frequency = fundamental.frequency_hz();
+
+    (1..=count)
+        .map(|n| frequency * n as f64)
+        .map_while(|harmonic| Pitch::from_frequency_hz(harmonic).map(|pitch| (harmonic, pitch)))
+        .collect()
+}
+
 /// Constants for common musical pitches in octave 4.
 pub(crate) mod constants {
-    use super::Pitch;
+    use super::{MAX_SEMITONES, Pitch, PitchRange};
     use paste::paste;
 
+    /// Frequency of A4 (MIDI note 69) in Hz, the reference for standard tuning.
+    pub const A4_FREQUENCY_HZ: f64 = 440.0;
+
+    /// Playing range of the violin (G3 to A7)
+    pub const VIOLIN_RANGE: PitchRange = PitchRange::new(G3, A7);
+    /// Playing range of the cello (C2 to A5)
+    pub const CELLO_RANGE: PitchRange = PitchRange::new(C2, A5);
+    /// Playing range of the flute (C4 to C7)
+    pub const FLUTE_RANGE: PitchRange = PitchRange::new(C4, C7);
+
+    /// Typical range of a soprano voice (C4 to A5)
+    pub const SOPRANO_RANGE: PitchRange = PitchRange::new(C4, A5);
+    /// Typical range of an alto voice (G3 to D5)
+    pub const ALTO_RANGE: PitchRange = PitchRange::new(G3, D5);
+    /// Typical range of a tenor voice (C3 to G4)
+    pub const TENOR_RANGE: PitchRange = PitchRange::new(C3, G4);
+    /// Typical range of a bass voice (E2 to C4)
+    pub const BASS_RANGE: PitchRange = PitchRange::new(E2, C4);
+
     macro_rules! define_octave {
         ($octave:expr) => {
             paste! {
                 // Octave $octave pitches.
 
+                const _: () = assert!(
+                    ($octave + 1) * crate::core::SEMITONES_IN_OCTAVE as u16 + 11 <= MAX_SEMITONES as u16,
+                    "octave exceeds the MIDI pitch range"
+                );
+
                 pub const [<C $octave>]: Pitch = Pitch::new(($octave + 1) * crate::core::SEMITONES_IN_OCTAVE);
                 pub const [<CSHARP $octave>]: Pitch = Pitch::new(($octave + 1) * crate::core::SEMITONES_IN_OCTAVE + 1);
                 pub const [<DFLAT $octave>]: Pitch = [<CSHARP $octave>];
@@ -263,7 +687,19 @@ pub(crate) mod constants {
     define_octave!(6);
     define_octave!(7);
     define_octave!(8);
-    define_octave!(9);
+
+    // Octave 9 is cut short at G9, the highest MIDI pitch.
+    pub const C9: Pitch = Pitch::new(120);
+    pub const CSHARP9: Pitch = Pitch::new(121);
+    pub const DFLAT9: Pitch = CSHARP9;
+    pub const D9: Pitch = Pitch::new(122);
+    pub const DSHARP9: Pitch = Pitch::new(123);
+    pub const EFLAT9: Pitch = DSHARP9;
+    pub const E9: Pitch = Pitch::new(124);
+    pub const F9: Pitch = Pitch::new(125);
+    pub const FSHARP9: Pitch = Pitch::new(126);
+    pub const GFLAT9: Pitch = FSHARP9;
+    pub const G9: Pitch = Pitch::MAX;
 }
 
 #[cfg(test)]
@@ -271,6 +707,116 @@ mod tests {
     use super::constants::*;
     use super::*;
 
+    #[test]
+    fn test_pitch_constants_in_midi_range() {
+        macro_rules! octave {
+            ($($octave:literal)?) => {
+                paste::paste! {
+                    [
+                        [<C $($octave)?>], [<CSHARP $($octave)?>], [<D $($octave)?>],
+                        [<DSHARP $($octave)?>], [<E $($octave)?>], [<F $($octave)?>],
+                        [<FSHARP $($octave)?>], [<G $($octave)?>], [<GSHARP $($octave)?>],
+                        [<A $($octave)?>], [<ASHARP $($octave)?>], [<B $($octave)?>],
+                    ]
+                }
+            };
+        }
+
+        let pitches: Vec<Pitch> = [
+            octave!(),
+            octave!(0),
+            octave!(1),
+            octave!(2),
+            octave!(3),
+            octave!(4),
+            octave!(5),
+            octave!(6),
+            octave!(7),
+            octave!(8),
+        ]
+        .concat()
+        .into_iter()
+        .chain([C9, CSHARP9, D9, DSHARP9, E9, F9, FSHARP9, G9])
+        .collect();
+
+        assert!(
+            pitches
+                .iter()
+                .all(|pitch| pitch.semitones() <= MAX_SEMITONES)
+        );
+        assert!(pitches.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(pitches.len(), usize::from(MAX_SEMITONES) + 1);
+        assert_eq!(G9.semitones(), 127);
+    }
+
+    #[test]
+    fn test_pitch_max_is_g9() {
+        assert_eq!(Pitch::MAX, G9);
+        assert_eq!(Pitch::MAX.semitones(), 127);
+    }
+
+    #[test]
+    fn test_frequency_with_standard_tuning() {
+        let tuning = TuningStandard::default();
+        assert_eq!(tuning.a4_frequency_hz(), A4_FREQUENCY_HZ);
+        assert_eq!(A4.frequency_hz_with(tuning), 440.0);
+        assert!((A5.frequency_hz_with(tuning) - 880.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_frequency_with_432_tuning() {
+        let tuning = TuningStandard::new(432.0);
+        assert_eq!(A4.frequency_hz_with(tuning), 432.0);
+        assert!((A3.frequency_hz_with(tuning) - 216.0).abs() < 1e-9);
+        assert!((C5.frequency_hz_with(tuning) - 513.737).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_pitch_range_contains() {
+        assert!(VIOLIN_RANGE.contains(G3));
+        assert!(VIOLIN_RANGE.contains(A7));
+        assert!(!VIOLIN_RANGE.contains(FSHARP3));
+        assert!(CELLO_RANGE.contains(C2));
+    }
+
+    #[test]
+    fn test_pitch_range_clamp() {
+        assert_eq!(VIOLIN_RANGE.clamp(C2), G3);
+        assert_eq!(FLUTE_RANGE.clamp(G9), C7);
+        assert_eq!(FLUTE_RANGE.clamp(A4), A4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pitch_range_inverted_bounds() {
+        PitchRange::new(C5, C4);
+    }
+
+    #[test]
+    fn test_enharmonics() {
+        assert_eq!(Pitch::new(60).enharmonics(), vec!["C4", "B#3", "Dbb4"]);
+        assert_eq!(Pitch::new(61).enharmonics()[..2], ["C#4", "Db4"]);
+        assert_eq!(EFLAT4.enharmonics()[..2], ["D#4", "Eb4"]);
+        assert_eq!(F4.enharmonics(), vec!["F4", "E#4", "Gbb4"]);
+    }
+
+    #[test]
+    fn test_shift_octave() {
+        assert_eq!(C4.shift_octave(1), Some(C5));
+        assert_eq!(C4.shift_octave(-5), Some(C));
+        assert_eq!(G4.shift_octave(0), Some(G4));
+        assert_eq!(C4.shift_octave(10), None);
+        assert_eq!(C4.shift_octave(-6), None);
+    }
+
+    #[test]
+    fn test_interval_class() {
+        assert_eq!(C4.interval_class(B4), 1);
+        assert_eq!(C4.interval_class(FSHARP4), 6);
+        assert_eq!(C4.interval_class(G5), 5);
+        assert_eq!(E4.interval_class(E2), 0);
+    }
+
     #[test]
     fn test_pitch_creation() {
         let pitch = Pitch::new(60);
@@ -343,6 +889,95 @@ mod tests {
         assert_eq!(steps, [Step::new(2), Step::new(3), Step::new(4)]);
     }
 
+    #[test]
+    fn test_pitch_display() {
+        assert_eq!(C4.to_string(), "C4");
+        assert_eq!(CSHARP4.to_string(), "C#4");
+        assert_eq!(B3.to_string(), "B3");
+        assert_eq!(Pitch::new(0).to_string(), "C-1");
+    }
+
+    #[test]
+    fn test_micro_pitch_display() {
+        assert_eq!(MicroPitch::new(C4, 50).to_string(), "C4+50c");
+        assert_eq!(MicroPitch::new(FSHARP4, -50).to_string(), "F#4-50c");
+    }
+
+    #[test]
+    fn test_micro_pitch_without_offset_display() {
+        assert_eq!(MicroPitch::new(C4, 0).to_string(), "C4");
+        assert_eq!(MicroPitch::from(A4).to_string(), "A4");
+    }
+
+    #[test]
+    fn test_frequency_hz() {
+        assert_eq!(A4.frequency_hz(), 440.0);
+        assert_eq!(A5.frequency_hz(), 880.0);
+        assert_eq!(A3.frequency_hz(), 220.0);
+        assert!((C4.frequency_hz() - 261.626).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_from_frequency_hz() {
+        assert_eq!(Pitch::from_frequency_hz(440.0), Some(A4));
+        assert_eq!(Pitch::from_frequency_hz(445.0), Some(A4));
+        assert_eq!(Pitch::from_frequency_hz(261.0), Some(C4));
+        assert_eq!(Pitch::from_frequency_hz(20_000.0), None);
+    }
+
+    #[test]
+    fn test_harmonic_series() {
+        let series = harmonic_series(A2, 3);
+        assert_eq!(series.len(), 3);
+
+        assert_eq!(series[0], (110.0, A2));
+        assert_eq!(series[1], (220.0, A3));
+
+        let (frequency, pitch) = series[2];
+        assert!((frequency - 330.0).abs() < 1e-9);
+        assert_eq!(pitch, E4);
+        assert!(frequency > E4.frequency_hz());
+    }
+
+    #[test]
+    fn test_harmonic_series_stops_at_midi_range() {
+        assert_eq!(harmonic_series(C8, 4).len(), 3);
+        assert!(harmonic_series(C4, 0).is_empty());
+    }
+
+    #[test]
+    fn test_melodic_intervals() {
+        use crate::core::constants::{MINOR_THIRD, PERFECT_FIFTH};
+
+        let intervals = melodic_intervals(&[C4, G4, E4]);
+        assert_eq!(intervals, vec![PERFECT_FIFTH, MINOR_THIRD]);
+    }
+
+    #[test]
+    fn test_melodic_intervals_short_melody() {
+        assert!(melodic_intervals(&[]).is_empty());
+        assert!(melodic_intervals(&[C4]).is_empty());
+    }
+
+    #[test]
+    fn test_largest_leap() {
+        use crate::core::constants::PERFECT_OCTAVE;
+
+        let melody = [C4, D4, E4, E5, D5, C5];
+        assert_eq!(largest_leap(&melody), Some(PERFECT_OCTAVE));
+    }
+
+    #[test]
+    fn test_largest_leap_descending() {
+        assert_eq!(largest_leap(&[G4, C4, D4]), Some(Interval::new(7)));
+    }
+
+    #[test]
+    fn test_largest_leap_short_melody() {
+        assert_eq!(largest_leap(&[]), None);
+        assert_eq!(largest_leap(&[C4]), None);
+    }
+
     #[test]
     fn test_constants() {
         assert_eq!(C4.semitones(), 60);
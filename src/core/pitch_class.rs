@@ -0,0 +1,263 @@
+//! Pitch class module for handling octave-independent pitches
+//!
+//! A pitch class groups together all pitches that are a whole number of octaves apart.
+//! There are twelve pitch classes, numbered 0 (C) through 11 (B). This module provides
+//! the `PitchClass` type used by set-theoretic and serial techniques, and the
+//! `PitchClassSet` type holding an unordered collection of pitch classes.
+
+use super::{Pitch, SEMITONES_IN_OCTAVE};
+use crate::scales::{ScaleInPitches, ScaleQuality};
+
+/// Bit mask covering the twelve pitch classes of a `PitchClassSet`.
+const ALL_PITCH_CLASSES: u16 = (1 << SEMITONES_IN_OCTAVE) - 1;
+
+/// Represents a pitch class, a pitch without its octave.
+///
+/// Pitch classes are numbered from 0 to 11, where:
+/// - 0: C
+/// - 1: C♯/D♭
+/// - 2: D
+/// - And so on up to 11: B
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PitchClass(u8);
+
+impl PitchClass {
+    /// Creates a new pitch class from a number of semitones above C.
+    ///
+    /// # Arguments
+    ///
+    /// * `semitones` - The number of semitones above C, reduced modulo the octave
+    ///
+    /// # Returns
+    ///
+    /// A new PitchClass in the range 0-11
+    #[inline]
+    pub const fn new(semitones: u8) -> Self {
+        Self(semitones % SEMITONES_IN_OCTAVE)
+    }
+
+    /// Returns the number of semitones above C of this pitch class.
+    ///
+    /// # Returns
+    ///
+    /// The pitch class number as a u8 (0-11)
+    #[inline]
+    pub const fn semitones(&self) -> u8 {
+        self.0
+    }
+
+    /// Transposes the pitch class up by a number of semitones, wrapping around the octave.
+    ///
+    /// # Arguments
+    ///
+    /// * `semitones` - The number of semitones to transpose by
+    ///
+    /// # Returns
+    ///
+    /// The transposed pitch class
+    #[inline]
+    pub const fn transpose(&self, semitones: u8) -> Self {
+        Self::new(self.0 + semitones % SEMITONES_IN_OCTAVE)
+    }
+}
+
+impl Default for PitchClass {
+    /// Returns the pitch class of C (0) as the default value.
+    #[inline]
+    fn default() -> Self {
+        Self(0)
+    }
+}
+
+impl From<Pitch> for PitchClass {
+    /// Converts a pitch into its pitch class.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to convert
+    ///
+    /// # Returns
+    ///
+    /// The pitch class of the pitch, ignoring its octave
+    #[inline]
+    fn from(pitch: Pitch) -> Self {
+        Self::new(pitch.semitones())
+    }
+}
+
+/// Represents a set of pitch classes, packed into the lowest twelve bits of a u16.
+///
+/// Bit 0 stands for C, bit 1 for C♯/D♭, and so on up to bit 11 for B.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct PitchClassSet(u16);
+
+impl PitchClassSet {
+    /// Creates a pitch-class set from its bit representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `bits` - The bits of the set, bit 0 being C; bits above 11 are ignored
+    ///
+    /// # Returns
+    ///
+    /// A new PitchClassSet
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits & ALL_PITCH_CLASSES)
+    }
+
+    /// Returns the bit representation of the set.
+    ///
+    /// # Returns
+    ///
+    /// The bits of the set, bit 0 being C
+    #[inline]
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the set with a pitch class added.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch_class` - The pitch class to add
+    ///
+    /// # Returns
+    ///
+    /// The set containing `pitch_class`
+    #[inline]
+    pub const fn with(&self, pitch_class: PitchClass) -> Self {
+        Self(self.0 | 1 << pitch_class.semitones())
+    }
+
+    /// Returns true if the set contains a pitch class.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch_class` - The pitch class to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if the pitch class is in the set, `false` otherwise
+    #[inline]
+    pub const fn contains(&self, pitch_class: PitchClass) -> bool {
+        self.0 & 1 << pitch_class.semitones() != 0
+    }
+
+    /// Returns the number of pitch classes in the set.
+    ///
+    /// # Returns
+    ///
+    /// The number of pitch classes (0-12)
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns true if the set has no pitch classes.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the set is empty, `false` otherwise
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if every pitch class of this set is also in another set.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The set to compare against
+    ///
+    /// # Returns
+    ///
+    /// `true` if this set is a subset of `other`, `false` otherwise
+    #[inline]
+    pub const fn is_subset(&self, other: &PitchClassSet) -> bool {
+        self.0 & other.0 == self.0
+    }
+}
+
+impl FromIterator<PitchClass> for PitchClassSet {
+    /// Collects pitch classes into a set, ignoring duplicates.
+    fn from_iter<I: IntoIterator<Item = PitchClass>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::default(), |set, pitch_class| set.with(pitch_class))
+    }
+}
+
+impl FromIterator<Pitch> for PitchClassSet {
+    /// Collects the pitch classes of pitches into a set, ignoring octaves and duplicates.
+    fn from_iter<I: IntoIterator<Item = Pitch>>(iter: I) -> Self {
+        iter.into_iter().map(PitchClass::from).collect()
+    }
+}
+
+impl<Q: ScaleQuality, const N: usize> From<&ScaleInPitches<Q, N>> for PitchClassSet {
+    /// Converts a scale into the set of its pitch classes.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale to convert
+    ///
+    /// # Returns
+    ///
+    /// The set of the pitch classes of the scale, ignoring octaves
+    fn from(scale: &ScaleInPitches<Q, N>) -> Self {
+        scale.items().iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::*;
+
+    #[test]
+    fn test_pitch_class_creation() {
+        let pitch_class = PitchClass::new(7);
+        assert_eq!(pitch_class.semitones(), 7);
+    }
+
+    #[test]
+    fn test_pitch_class_wraps_octave() {
+        assert_eq!(PitchClass::new(12), PitchClass::new(0));
+        assert_eq!(PitchClass::new(25), PitchClass::new(1));
+    }
+
+    #[test]
+    fn test_pitch_class_default() {
+        assert_eq!(PitchClass::default().semitones(), 0);
+    }
+
+    #[test]
+    fn test_pitch_class_from_pitch() {
+        assert_eq!(PitchClass::from(C4), PitchClass::new(0));
+        assert_eq!(PitchClass::from(FSHARP2), PitchClass::new(6));
+        assert_eq!(PitchClass::from(B8), PitchClass::new(11));
+    }
+
+    #[test]
+    fn test_pitch_class_transpose() {
+        assert_eq!(PitchClass::new(10).transpose(3), PitchClass::new(1));
+        assert_eq!(PitchClass::new(4).transpose(24), PitchClass::new(4));
+    }
+
+    #[test]
+    fn test_pitch_class_set_from_scale() {
+        let set = PitchClassSet::from(&crate::scales::major_scale(C4));
+        assert_eq!(set.len(), 7);
+        assert_eq!(set.bits().count_ones(), 7);
+        assert!(set.contains(PitchClass::new(11)));
+        assert!(!set.contains(PitchClass::new(1)));
+    }
+
+    #[test]
+    fn test_pitch_class_set_from_pitches() {
+        let set: PitchClassSet = [C4, E4, G4, C5].into_iter().collect();
+        assert_eq!(set, PitchClassSet::from_bits(0b1001_0001));
+        assert!(set.is_subset(&PitchClassSet::from(&crate::scales::major_scale(C2))));
+        assert!(PitchClassSet::default().is_empty());
+    }
+}
@@ -0,0 +1,313 @@
+//! Serialism module for twelve-tone composition techniques
+//!
+//! In twelve-tone technique a tone row is transformed through retrograde and inversion
+//! (and their combination). This module provides those transformations for tone rows
+//! expressed as interval sequences, where each interval is measured from the first
+//! pitch of the row, as well as the `ToneRow` type holding an ordering of all twelve
+//! pitch classes.
+
+use super::{Interval, PitchClass, SEMITONES_IN_OCTAVE};
+
+/// The number of pitch classes in a twelve-tone row.
+const ROW_LENGTH: usize = SEMITONES_IN_OCTAVE as usize;
+
+/// Returns the retrograde of an interval sequence.
+///
+/// # Arguments
+///
+/// * `intervals` - The interval sequence representing a tone row
+///
+/// # Returns
+///
+/// The same intervals in reverse order
+///
+/// # Examples
+///
+/// ```rust
+/// use no_surprises::prelude::*;
+///
+/// let row = [MAJOR_SECOND, MINOR_THIRD, PERFECT_FIFTH];
+/// assert_eq!(retrograde(row), [PERFECT_FIFTH, MINOR_THIRD, MAJOR_SECOND]);
+/// ```
+pub fn retrograde<const N: usize>(intervals: [Interval; N]) -> [Interval; N] {
+    let mut reversed = intervals;
+    reversed.reverse();
+    reversed
+}
+
+/// Returns the inversion of an interval sequence.
+///
+/// Each interval is measured from the first pitch of the row, so inverting the row
+/// negates every interval around that pitch, modulo the octave. An interval of `n`
+/// semitones becomes `12 - n`, and a unison stays a unison.
+///
+/// # Arguments
+///
+/// * `intervals` - The interval sequence representing a tone row
+///
+/// # Returns
+///
+/// The inverted intervals, each within a single octave
+///
+/// # Examples
+///
+/// ```rust
+/// use no_surprises::prelude::*;
+///
+/// let row = [MAJOR_SECOND, MINOR_THIRD, PERFECT_FIFTH];
+/// assert_eq!(invert_row(row), [MINOR_SEVENTH, MAJOR_SIXTH, PERFECT_FOURTH]);
+/// ```
+pub fn invert_row<const N: usize>(intervals: [Interval; N]) -> [Interval; N] {
+    intervals.map(|interval| {
+        let class = interval.semitones() % SEMITONES_IN_OCTAVE;
+        Interval::new((SEMITONES_IN_OCTAVE - class) % SEMITONES_IN_OCTAVE)
+    })
+}
+
+/// Represents a twelve-tone row, an ordering of all twelve pitch classes.
+///
+/// Every pitch class appears exactly once in the row. The four standard forms of
+/// the row (prime, retrograde, inversion and retrograde-inversion) and their
+/// transpositions are again valid rows.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ToneRow([PitchClass; ROW_LENGTH]);
+
+impl ToneRow {
+    /// Creates a new tone row from an ordering of pitch classes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch_classes` - The twelve pitch classes of the row, in order
+    ///
+    /// # Returns
+    ///
+    /// `Some(ToneRow)` if every pitch class appears exactly once, `None` otherwise
+    pub fn new(pitch_classes: [PitchClass; ROW_LENGTH]) -> Option<Self> {
+        let mut seen = [false; ROW_LENGTH];
+
+        for pitch_class in pitch_classes {
+            let index = pitch_class.semitones() as usize;
+            if seen[index] {
+                return None;
+            }
+            seen[index] = true;
+        }
+
+        Some(Self(pitch_classes))
+    }
+
+    /// Returns a reference to the pitch classes of the row.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the array of pitch classes, in row order
+    #[inline]
+    pub const fn pitch_classes(&self) -> &[PitchClass; ROW_LENGTH] {
+        &self.0
+    }
+
+    /// Returns the prime form of the row, which is the row itself.
+    ///
+    /// # Returns
+    ///
+    /// A copy of the row
+    #[inline]
+    pub fn prime(&self) -> Self {
+        *self
+    }
+
+    /// Returns the retrograde of the row, the pitch classes in reverse order.
+    ///
+    /// # Returns
+    ///
+    /// A new row with the pitch classes reversed
+    #[inline]
+    pub fn retrograde(&self) -> Self {
+        let mut pitch_classes = self.0;
+        pitch_classes.reverse();
+        Self(pitch_classes)
+    }
+
+    /// Returns the inversion of the row around its first pitch class.
+    ///
+    /// Every interval from the first pitch class is mirrored, so a row moving up
+    /// by a minor second moves down by a minor second in its inversion.
+    ///
+    /// # Returns
+    ///
+    /// A new row starting on the same pitch class with all intervals inverted
+    pub fn inversion(&self) -> Self {
+        let axis = self.0[0].semitones();
+
+        Self(self.0.map(|pitch_class| {
+            let interval =
+                (pitch_class.semitones() + SEMITONES_IN_OCTAVE - axis) % SEMITONES_IN_OCTAVE;
+            PitchClass::new(axis + SEMITONES_IN_OCTAVE - interval)
+        }))
+    }
+
+    /// Returns the retrograde of the inversion of the row.
+    ///
+    /// # Returns
+    ///
+    /// A new row with the inversion's pitch classes reversed
+    #[inline]
+    pub fn retrograde_inversion(&self) -> Self {
+        self.inversion().retrograde()
+    }
+
+    /// Transposes every pitch class of the row up by a number of semitones.
+    ///
+    /// # Arguments
+    ///
+    /// * `semitones` - The number of semitones to transpose by
+    ///
+    /// # Returns
+    ///
+    /// A new row with every pitch class transposed
+    #[inline]
+    pub fn transpose(&self, semitones: u8) -> Self {
+        Self(self.0.map(|pitch_class| pitch_class.transpose(semitones)))
+    }
+
+    /// Returns the twelve-tone matrix of the row.
+    ///
+    /// The top row of the matrix is the prime form and the left column is the
+    /// inversion. Every row of the matrix is the prime form transposed to start on
+    /// the corresponding pitch class of the inversion, so reading rows left to right
+    /// gives the transpositions of the prime and reading columns top to bottom gives
+    /// the transpositions of the inversion.
+    ///
+    /// # Returns
+    ///
+    /// A 12x12 array of pitch classes, indexed by row and then column
+    pub fn matrix(&self) -> [[PitchClass; ROW_LENGTH]; ROW_LENGTH] {
+        let first = self.0[0].semitones();
+
+        self.inversion().0.map(|start| {
+            let distance = (start.semitones() + SEMITONES_IN_OCTAVE - first) % SEMITONES_IN_OCTAVE;
+            self.transpose(distance).0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::*;
+
+    #[test]
+    fn test_retrograde() {
+        let row = [MINOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SEVENTH];
+        assert_eq!(
+            retrograde(row),
+            [MAJOR_SEVENTH, PERFECT_FIFTH, MAJOR_THIRD, MINOR_SECOND]
+        );
+    }
+
+    #[test]
+    fn test_retrograde_twice_is_identity() {
+        let row = [MINOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH];
+        assert_eq!(retrograde(retrograde(row)), row);
+    }
+
+    #[test]
+    fn test_invert_row() {
+        let row = [MINOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, AUGMENTED_FOURTH];
+        assert_eq!(
+            invert_row(row),
+            [MAJOR_SEVENTH, MINOR_SIXTH, PERFECT_FOURTH, DIMINISHED_FIFTH]
+        );
+    }
+
+    #[test]
+    fn test_invert_row_keeps_unison() {
+        let row = [PERFECT_UNISON, PERFECT_OCTAVE];
+        assert_eq!(invert_row(row), [PERFECT_UNISON, PERFECT_UNISON]);
+    }
+
+    #[test]
+    fn test_empty_row() {
+        let row: [Interval; 0] = [];
+        assert_eq!(retrograde(row).len(), 0);
+        assert_eq!(invert_row(row).len(), 0);
+    }
+
+    fn row(semitones: [u8; 12]) -> [PitchClass; 12] {
+        semitones.map(PitchClass::new)
+    }
+
+    #[test]
+    fn test_tone_row_creation() {
+        let pitch_classes = row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9]);
+        let tone_row = ToneRow::new(pitch_classes).unwrap();
+        assert_eq!(tone_row.pitch_classes(), &pitch_classes);
+        assert_eq!(tone_row.prime(), tone_row);
+    }
+
+    #[test]
+    fn test_tone_row_rejects_duplicate() {
+        let pitch_classes = row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 4]);
+        assert_eq!(ToneRow::new(pitch_classes), None);
+    }
+
+    #[test]
+    fn test_tone_row_retrograde() {
+        let tone_row = ToneRow::new(row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9])).unwrap();
+        assert_eq!(
+            tone_row.retrograde().pitch_classes(),
+            &row([9, 4, 5, 6, 10, 2, 1, 3, 8, 7, 11, 0])
+        );
+    }
+
+    #[test]
+    fn test_tone_row_inversion() {
+        let tone_row = ToneRow::new(row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9])).unwrap();
+        assert_eq!(
+            tone_row.inversion().pitch_classes(),
+            &row([0, 1, 5, 4, 9, 11, 10, 2, 6, 7, 8, 3])
+        );
+    }
+
+    #[test]
+    fn test_tone_row_retrograde_inversion() {
+        let tone_row = ToneRow::new(row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9])).unwrap();
+        assert_eq!(
+            tone_row.retrograde_inversion().pitch_classes(),
+            &row([3, 8, 7, 6, 2, 10, 11, 9, 4, 5, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_tone_row_matrix() {
+        let tone_row = ToneRow::new(row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9])).unwrap();
+        let matrix = tone_row.matrix();
+
+        assert_eq!(&matrix[0], tone_row.pitch_classes());
+
+        let left_column: [PitchClass; 12] = std::array::from_fn(|i| matrix[i][0]);
+        assert_eq!(&left_column, tone_row.inversion().pitch_classes());
+
+        assert!((0..12).all(|i| matrix[i][i] == matrix[0][0]));
+    }
+
+    #[test]
+    fn test_tone_row_matrix_rows_are_transpositions() {
+        let tone_row = ToneRow::new(row([4, 5, 7, 1, 6, 3, 8, 2, 11, 0, 9, 10])).unwrap();
+        let matrix = tone_row.matrix();
+
+        for matrix_row in matrix {
+            assert!(ToneRow::new(matrix_row).is_some());
+        }
+        assert_eq!(&matrix[1], tone_row.transpose(11).pitch_classes());
+    }
+
+    #[test]
+    fn test_tone_row_transpose() {
+        let tone_row = ToneRow::new(row([0, 11, 7, 8, 3, 1, 2, 10, 6, 5, 4, 9])).unwrap();
+        assert_eq!(
+            tone_row.transpose(2).pitch_classes(),
+            &row([2, 1, 9, 10, 5, 3, 4, 0, 8, 7, 6, 11])
+        );
+    }
+}
@@ -4,6 +4,8 @@
 //! This module provides functionality for working with steps, including conversion
 //! to and from intervals, and operations on collections of steps.
 
+use std::ops::Neg;
+
 use crate::UNISON;
 
 use super::{Interval, IntoIntervals, IntoPitches, Pitch};
@@ -39,6 +41,75 @@ impl Step {
     pub const fn semitones(&self) -> u8 {
         self.0
     }
+
+    /// Decomposes this step into whole steps and a leftover half step.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (whole steps, leftover half steps), e.g. `(1, 1)` for a whole and
+    /// half step
+    #[inline]
+    pub const fn decompose(&self) -> (u8, u8) {
+        (self.0 / 2, self.0 % 2)
+    }
+}
+
+/// Represents a step with a direction, measured in semitones.
+///
+/// Positive values move up and negative values move down, so a directed step can
+/// describe descending motion that a `Step` cannot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct DirectedStep(i8);
+
+impl DirectedStep {
+    /// Creates a new directed step with the given number of semitones.
+    ///
+    /// # Arguments
+    ///
+    /// * `semitones` - The number of semitones, negative for a downward step
+    ///
+    /// # Returns
+    ///
+    /// A new DirectedStep with the specified number of semitones
+    #[inline]
+    pub const fn new(semitones: i8) -> Self {
+        Self(semitones)
+    }
+
+    /// Returns the signed number of semitones in this directed step.
+    ///
+    /// # Returns
+    ///
+    /// The number of semitones as an i8, negative for a downward step
+    #[inline]
+    pub const fn semitones(&self) -> i8 {
+        self.0
+    }
+
+    /// Returns the size of this directed step, ignoring its direction.
+    ///
+    /// # Returns
+    ///
+    /// The undirected step with the same number of semitones
+    #[inline]
+    pub const fn magnitude(&self) -> Step {
+        Step::new(self.0.unsigned_abs())
+    }
+}
+
+impl Neg for DirectedStep {
+    type Output = Self;
+
+    /// Flips the direction of a directed step, keeping its size.
+    ///
+    /// # Returns
+    ///
+    /// The directed step of the same size in the opposite direction, saturating at
+    /// `i8::MAX` for the largest downward step
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0.saturating_neg())
+    }
 }
 
 impl Default for Step {
@@ -160,6 +231,47 @@ impl<const N: usize> IntoPitches for [Step; N] {
     }
 }
 
+/// Reflects a step pattern by reversing the order of its steps.
+///
+/// Building a scale on the reflected pattern gives the mirror image of the original
+/// scale, e.g. reflecting the major pattern gives the Phrygian pattern.
+///
+/// # Arguments
+///
+/// * `steps` - The step pattern to reflect
+///
+/// # Returns
+///
+/// The steps of the pattern in reverse order
+pub fn reflect_steps<const N: usize>(steps: [Step; N]) -> [Step; N] {
+    let mut reflected = steps;
+    reflected.reverse();
+    reflected
+}
+
+/// Concatenates two step patterns into one.
+///
+/// This builds longer patterns from shorter ones, e.g. a heptatonic scale from two
+/// tetrachords joined by a whole step.
+///
+/// # Arguments
+///
+/// * `a` - The steps that come first
+/// * `b` - The steps that follow
+///
+/// # Returns
+///
+/// The steps of `a` followed by the steps of `b`
+pub fn concat_steps<const A: usize, const B: usize>(a: [Step; A], b: [Step; B]) -> [Step; A + B]
+where
+    [(); A + B]:,
+{
+    let mut steps = [UNISON; A + B];
+    steps[..A].copy_from_slice(&a);
+    steps[A..].copy_from_slice(&b);
+    steps
+}
+
 /// Constants for common musical steps.
 pub(crate) mod constants {
     use super::Step;
@@ -183,6 +295,43 @@ mod tests {
     use super::constants::*;
     use super::*;
 
+    #[test]
+    fn test_step_decompose() {
+        assert_eq!(WHOLE_AND_HALF.decompose(), (1, 1));
+        assert_eq!(WHOLE.decompose(), (1, 0));
+        assert_eq!(Step::new(7).decompose(), (3, 1));
+        assert_eq!(UNISON.decompose(), (0, 0));
+    }
+
+    #[test]
+    fn test_directed_step_neg() {
+        let up = DirectedStep::new(2);
+        let down = -up;
+        assert_eq!(down.semitones(), -2);
+        assert_eq!(down.magnitude(), up.magnitude());
+        assert_eq!(down.magnitude(), WHOLE);
+        assert_eq!(-down, up);
+    }
+
+    #[test]
+    fn test_concat_tetrachords() {
+        let tetrachord = [WHOLE, WHOLE, HALF];
+        let lower = concat_steps(tetrachord, [WHOLE]);
+        assert_eq!(
+            concat_steps(lower, tetrachord),
+            [WHOLE, WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF]
+        );
+        assert_eq!(concat_steps([], [HALF]), [HALF]);
+    }
+
+    #[test]
+    fn test_reflect_major_is_phrygian() {
+        let major = [WHOLE, WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF];
+        let phrygian = [HALF, WHOLE, WHOLE, WHOLE, HALF, WHOLE, WHOLE];
+        assert_eq!(reflect_steps(major), phrygian);
+        assert_eq!(reflect_steps(phrygian), major);
+    }
+
     #[test]
     fn test_step_creation() {
         let step = Step::new(2);
@@ -15,6 +15,8 @@
 //! # Examples
 //!
 //! ```rust
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs)]
 //! use no_surprises::prelude::*;
 //! use no_surprises::scales::major_scale;
 //!
@@ -57,16 +59,19 @@ pub mod scales;
 /// # Examples
 ///
 /// ```rust
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
 /// use no_surprises::prelude::*;
-/// use no_surprises::scales::major_scale;
 ///
 /// // Now you can use all the core types and constants directly
 /// let pitch = C4;
 /// let interval = MAJOR_THIRD;
 /// let step = HALF;
 ///
-/// // And work with scales
+/// // And work with scales, without importing them from the scales module
 /// let scale = major_scale(C4);
+/// assert_eq!(scale.pitches(), &[C4, D4, E4, F4, G4, A4, B4, C5]);
+/// let minor: NaturalMinorScalePitches = naturalminor_scale(A3);
 /// ```
 pub mod prelude {
     #[allow(unused_imports)]
@@ -74,6 +79,13 @@ pub mod prelude {
 
     #[allow(unused_imports)]
     pub use crate::core::constants::*;
+
+    #[allow(unused_imports)]
+    pub use crate::scales::*;
+
+    // Both modules have a `constants` submodule; the prelude keeps the core one.
+    #[allow(unused_imports)]
+    pub use crate::core::constants;
 }
 
 /// Re-export all items from the prelude for convenient access
@@ -0,0 +1,510 @@
+//! Scale analysis
+//!
+//! Functions that relate melodies, intervals and chords to the scales known to this
+//! crate, e.g. suggesting which scales a sequence of pitches could have been drawn from.
+
+use crate::core::{Chord, Interval, Pitch, PitchClass, PitchClassSet, SEMITONES_IN_OCTAVE, Step};
+
+use super::{
+    BLUES_SCALE_STEPS, DynScale, HARMONICMINOR_SCALE_STEPS, MAJOR_SCALE_STEPS,
+    MELODICMINOR_SCALE_STEPS, NATURALMINOR_SCALE_STEPS, degree_semitones,
+};
+
+/// The scales considered by the analysis functions, by name and step pattern
+const KNOWN_SCALES: [(&str, &[Step]); 5] = [
+    ("major", &MAJOR_SCALE_STEPS),
+    ("natural minor", &NATURALMINOR_SCALE_STEPS),
+    ("harmonic minor", &HARMONICMINOR_SCALE_STEPS),
+    ("melodic minor", &MELODICMINOR_SCALE_STEPS),
+    ("blues", &BLUES_SCALE_STEPS),
+];
+
+/// Returns the pitch-class set of a scale pattern built on a root.
+fn scale_pitch_classes(root: Pitch, steps: &[Step]) -> PitchClassSet {
+    let root = PitchClass::from(root);
+    degree_semitones(steps)
+        .map(|semitones| root.transpose(semitones))
+        .collect()
+}
+
+/// Suggests the keys a melody could have been drawn from.
+///
+/// Every known scale is tried on each of the twelve tonics, and a key matches when every
+/// pitch class of the melody belongs to it. The melody does not need to start on the
+/// tonic, so `[E4, F4, G4, A4]` matches C major among others.
+///
+/// # Arguments
+///
+/// * `pitches` - The melody to analyze
+///
+/// # Returns
+///
+/// The matching keys as (tonic, scale name) pairs, e.g. `(C, "major")`, ordered by tonic
+/// from C and then by scale, or an empty list for an empty melody
+pub fn detect_scale(pitches: &[Pitch]) -> Vec<(PitchClass, &'static str)> {
+    if pitches.is_empty() {
+        return Vec::new();
+    }
+
+    let melody: PitchClassSet = pitches.iter().copied().collect();
+    (0..SEMITONES_IN_OCTAVE)
+        .flat_map(|tonic| KNOWN_SCALES.iter().map(move |scale| (tonic, scale)))
+        .filter(|(tonic, (_, steps))| {
+            melody.is_subset(&scale_pitch_classes(Pitch::new(*tonic), steps))
+        })
+        .map(|(tonic, (name, _))| (PitchClass::new(tonic), *name))
+        .collect()
+}
+
+/// Lists the scales that contain a degree at a given interval above the tonic.
+///
+/// The tonic itself counts as a degree, so a perfect unison matches every scale, as does
+/// the perfect octave that closes it.
+///
+/// # Arguments
+///
+/// * `interval` - The interval of the degree, measured from the tonic
+///
+/// # Returns
+///
+/// The names of the scales with a degree at that interval, e.g. `"natural minor"` for
+/// a minor third
+pub fn scales_with_degree(interval: Interval) -> Vec<&'static str> {
+    KNOWN_SCALES
+        .iter()
+        .filter(|(_, steps)| {
+            degree_semitones(steps).any(|semitones| semitones == interval.semitones())
+        })
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Realizes the scale of a given quality that harmonizes a chord.
+///
+/// The scale is built on the root of the chord.
+///
+/// # Arguments
+///
+/// * `chord` - The chord to harmonize
+/// * `quality` - The name of a known scale, e.g. `"major"` or `"harmonic minor"`
+///
+/// # Returns
+///
+/// The pitches of the scale from the chord's root up to its octave, or an empty list if
+/// the quality is not a known scale
+pub fn scale_for_chord(chord: &Chord, quality: &str) -> Vec<Pitch> {
+    KNOWN_SCALES
+        .iter()
+        .find(|(name, _)| *name == quality)
+        .map(|(name, steps)| DynScale::new(*name, chord.root(), steps.to_vec()).pitches())
+        .unwrap_or_default()
+}
+
+/// Finds which mode of the major scale a step pattern is.
+///
+/// # Arguments
+///
+/// * `steps` - A heptatonic step pattern
+///
+/// # Returns
+///
+/// `Some` with the mode number, 1 for Ionian through 7 for Locrian, or `None` if the
+/// pattern is not a rotation of the major pattern
+pub fn major_mode_index(steps: &[Step]) -> Option<usize> {
+    if steps.len() != MAJOR_SCALE_STEPS.len() {
+        return None;
+    }
+
+    (0..MAJOR_SCALE_STEPS.len())
+        .find(|rotation| {
+            let mut mode = MAJOR_SCALE_STEPS;
+            mode.rotate_left(*rotation);
+            mode == steps
+        })
+        .map(|rotation| rotation + 1)
+}
+
+/// Finds the smallest transposition that maps a scale onto itself.
+///
+/// Symmetric scales such as the whole-tone scale (at 2 semitones) or the octatonic scale
+/// (at 3 semitones) keep the same pitch classes when transposed by less than an octave.
+///
+/// # Arguments
+///
+/// * `pitch_classes` - The pitch classes of the scale
+///
+/// # Returns
+///
+/// `Some` with the smallest such transposition in semitones, or `None` if the scale is
+/// empty or only maps onto itself at the octave
+pub fn is_symmetric(pitch_classes: &[PitchClass]) -> Option<u8> {
+    let set: PitchClassSet = pitch_classes.iter().copied().collect();
+    if set.is_empty() {
+        return None;
+    }
+
+    (1..SEMITONES_IN_OCTAVE).find(|semitones| {
+        let transposed: PitchClassSet = pitch_classes
+            .iter()
+            .map(|pitch_class| pitch_class.transpose(*semitones))
+            .collect();
+        transposed == set
+    })
+}
+
+/// Returns true if two scales are modes of one another.
+///
+/// Two scales are modes of one another when they contain the same pitch classes, so one
+/// is a rotation of the other. For example D Dorian and C major are modes.
+///
+/// # Arguments
+///
+/// * `a` - The pitch classes of the first scale
+/// * `b` - The pitch classes of the second scale
+///
+/// # Returns
+///
+/// `true` if both scales have the same pitch-class set, `false` otherwise
+pub fn are_modes(a: &[PitchClass], b: &[PitchClass]) -> bool {
+    let a: PitchClassSet = a.iter().copied().collect();
+    let b: PitchClassSet = b.iter().copied().collect();
+    a == b
+}
+
+/// Removes scales that share a pitch-class set with an earlier scale.
+///
+/// Modes of one another, such as C major and D Dorian, collapse into the first one
+/// listed. Transpositions to a different key keep their own pitch classes and are kept,
+/// so C major and G major both survive.
+///
+/// # Arguments
+///
+/// * `scales` - The pitch classes of each scale
+///
+/// # Returns
+///
+/// The scales with a pitch-class set not seen before, in their original order
+pub fn dedupe_by_pitch_class_set(scales: &[Vec<PitchClass>]) -> Vec<Vec<PitchClass>> {
+    let mut seen: Vec<PitchClassSet> = Vec::new();
+    scales
+        .iter()
+        .filter(|scale| {
+            let set: PitchClassSet = scale.iter().copied().collect();
+            let is_new = !seen.contains(&set);
+            if is_new {
+                seen.push(set);
+            }
+            is_new
+        })
+        .cloned()
+        .collect()
+}
+
+/// Detects the major or minor key that best fits a collection of pitches.
+///
+/// Each of the 24 major and natural minor keys is scored by how many of the pitches
+/// belong to it. Ties, such as between relative keys, go to the key whose tonic occurs
+/// most often, then to major keys, then to the lowest tonic from C.
+///
+/// # Arguments
+///
+/// * `pitches` - The pitches to analyze, e.g. the notes of a melody
+///
+/// # Returns
+///
+/// The tonic of the best-fitting key and `true` if it is major, `false` if it is minor
+pub fn detect_key(pitches: &[Pitch]) -> (PitchClass, bool) {
+    let score = |(tonic, is_major): (PitchClass, bool)| {
+        let steps: &[Step] = if is_major {
+            &MAJOR_SCALE_STEPS
+        } else {
+            &NATURALMINOR_SCALE_STEPS
+        };
+        let key = scale_pitch_classes(Pitch::new(tonic.semitones()), steps);
+        let pitch_classes = pitches.iter().map(|pitch| PitchClass::from(*pitch));
+
+        let fits = pitch_classes
+            .clone()
+            .filter(|pitch_class| key.contains(*pitch_class))
+            .count();
+        let tonics = pitch_classes
+            .filter(|pitch_class| *pitch_class == tonic)
+            .count();
+        (fits, tonics, is_major)
+    };
+
+    [true, false]
+        .into_iter()
+        .flat_map(|is_major| {
+            (0..SEMITONES_IN_OCTAVE).map(move |semitones| (PitchClass::new(semitones), is_major))
+        })
+        .fold(None, |best: Option<((PitchClass, bool), _)>, key| {
+            let key_score = score(key);
+            match best {
+                Some((_, best_score)) if best_score >= key_score => best,
+                _ => Some((key, key_score)),
+            }
+        })
+        .map(|(key, _)| key)
+        .unwrap_or((PitchClass::new(0), true))
+}
+
+/// Scores how bright a heptatonic mode sounds compared to the major scale.
+///
+/// Each degree is measured in semitones above the tonic, the first pitch class, and
+/// compared to the same degree of the major scale. Raised degrees add to the score and
+/// lowered degrees subtract from it, so Lydian scores 1, Ionian 0 and Locrian -5. Degrees
+/// past the seventh, such as the octave repetition of the tonic, are ignored.
+///
+/// # Arguments
+///
+/// * `pitch_classes` - The pitch classes of the mode, starting from the tonic
+///
+/// # Returns
+///
+/// The brightness of the mode, higher for brighter modes
+pub fn mode_brightness(pitch_classes: &[PitchClass]) -> i32 {
+    let Some(tonic) = pitch_classes.first() else {
+        return 0;
+    };
+
+    let major_degrees = degree_semitones(&MAJOR_SCALE_STEPS)
+        .take(MAJOR_SCALE_STEPS.len())
+        .map(i32::from);
+
+    pitch_classes
+        .iter()
+        .map(|pitch_class| {
+            let semitones = pitch_class.semitones() + SEMITONES_IN_OCTAVE - tonic.semitones();
+            i32::from(semitones % SEMITONES_IN_OCTAVE)
+        })
+        .zip(major_degrees)
+        .map(|(degree, major_degree)| degree - major_degree)
+        .sum()
+}
+
+/// Inverts a sequence of pitches about an axis pitch.
+///
+/// Each pitch is reflected to the same distance on the other side of the axis, so rising
+/// motion becomes falling motion. Inverting a scale about its tonic gives its mirror image
+/// descending from the tonic, e.g. C major inverted about C4 is C Phrygian going down.
+///
+/// # Arguments
+///
+/// * `pitches` - The pitches to invert
+/// * `axis` - The pitch to reflect around
+///
+/// # Returns
+///
+/// The inverted pitches in the same order, leaving out those that would fall outside the
+/// MIDI range (0-127)
+pub fn invert_scale_about(pitches: &[Pitch], axis: Pitch) -> Vec<Pitch> {
+    pitches
+        .iter()
+        .filter_map(|pitch| {
+            let inverted = 2 * i16::from(axis.semitones()) - i16::from(pitch.semitones());
+            u8::try_from(inverted)
+                .ok()
+                .filter(|semitones| *semitones <= Pitch::MAX.semitones())
+                .map(Pitch::new)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::scales::{harmonicminor_scale, major_mode, major_scale, naturalminor_scale};
+
+    #[test]
+    fn test_detect_scale_major_fragment() {
+        let c = PitchClass::from(C4);
+        let scales = detect_scale(&[C4, D4, E4, G4]);
+        assert!(scales.contains(&(c, "major")));
+        assert!(!scales.contains(&(c, "natural minor")));
+    }
+
+    #[test]
+    fn test_detect_scale_fragment_not_starting_on_tonic() {
+        let scales = detect_scale(&[E4, F4, G4, A4]);
+        assert!(scales.contains(&(PitchClass::from(C4), "major")));
+        assert!(scales.contains(&(PitchClass::from(F4), "major")));
+        assert!(!scales.contains(&(PitchClass::from(E4), "major")));
+    }
+
+    #[test]
+    fn test_detect_scale_minor_fragment() {
+        let scales = detect_scale(&[A3, B3, C4, E4, F4, G4]);
+        assert!(scales.contains(&(PitchClass::from(A3), "natural minor")));
+        assert!(scales.contains(&(PitchClass::from(C4), "major")));
+        assert!(!scales.contains(&(PitchClass::from(A3), "harmonic minor")));
+    }
+
+    #[test]
+    fn test_detect_scale_harmonic_minor_fragment() {
+        let scales = detect_scale(&[A3, GSHARP3, A3, C4, F4]);
+        assert!(scales.contains(&(PitchClass::from(A3), "harmonic minor")));
+        assert!(!scales.contains(&(PitchClass::from(A3), "natural minor")));
+    }
+
+    #[test]
+    fn test_scales_with_degree() {
+        let scales = scales_with_degree(MINOR_THIRD);
+        assert!(scales.contains(&"natural minor"));
+        assert!(scales.contains(&"blues"));
+        assert!(!scales.contains(&"major"));
+    }
+
+    #[test]
+    fn test_scales_with_degree_unison_and_octave() {
+        assert_eq!(scales_with_degree(PERFECT_UNISON).len(), KNOWN_SCALES.len());
+        assert_eq!(scales_with_degree(PERFECT_OCTAVE).len(), KNOWN_SCALES.len());
+        assert!(scales_with_degree(MINOR_SECOND).is_empty());
+    }
+
+    #[test]
+    fn test_scale_for_chord() {
+        let chord = Chord::new(C4, vec![MAJOR_THIRD, PERFECT_FIFTH]);
+        assert_eq!(scale_for_chord(&chord, "major"), major_scale(C4).to_vec());
+        assert_eq!(
+            scale_for_chord(&chord.transpose(MAJOR_SIXTH).unwrap(), "natural minor"),
+            naturalminor_scale(A4).to_vec()
+        );
+        assert!(scale_for_chord(&chord, "lydian dominant").is_empty());
+    }
+
+    #[test]
+    fn test_major_mode_index() {
+        let dorian = [WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF, WHOLE];
+        assert_eq!(major_mode_index(&dorian), Some(2));
+        assert_eq!(major_mode_index(&NATURALMINOR_SCALE_STEPS), Some(6));
+        assert_eq!(major_mode_index(&MAJOR_SCALE_STEPS), Some(1));
+    }
+
+    #[test]
+    fn test_major_mode_index_no_match() {
+        assert_eq!(major_mode_index(&HARMONICMINOR_SCALE_STEPS), None);
+        assert_eq!(major_mode_index(&BLUES_SCALE_STEPS), None);
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let whole_tone: Vec<PitchClass> = (0..6).map(|i| PitchClass::new(i * 2)).collect();
+        assert_eq!(is_symmetric(&whole_tone), Some(2));
+
+        let octatonic: Vec<PitchClass> = [0, 1, 3, 4, 6, 7, 9, 10]
+            .into_iter()
+            .map(PitchClass::new)
+            .collect();
+        assert_eq!(is_symmetric(&octatonic), Some(3));
+    }
+
+    #[test]
+    fn test_is_not_symmetric() {
+        let c_major: Vec<PitchClass> = major_scale(C4)
+            .pitches()
+            .iter()
+            .copied()
+            .map(PitchClass::from)
+            .collect();
+        assert_eq!(is_symmetric(&c_major), None);
+        assert_eq!(is_symmetric(&[]), None);
+    }
+
+    #[test]
+    fn test_are_modes() {
+        let pitch_classes = |pitches: &[Pitch]| -> Vec<PitchClass> {
+            pitches.iter().copied().map(PitchClass::from).collect()
+        };
+        let c_major = pitch_classes(major_scale(C4).pitches());
+        let a_minor = pitch_classes(naturalminor_scale(A3).pitches());
+        let c_harmonic_minor = pitch_classes(harmonicminor_scale(C4).pitches());
+        let d_dorian = pitch_classes(&major_mode(2, D4));
+
+        assert!(are_modes(&c_major, &a_minor));
+        assert!(are_modes(&c_major, &d_dorian));
+        assert!(!are_modes(&c_major, &c_harmonic_minor));
+    }
+
+    #[test]
+    fn test_dedupe_by_pitch_class_set() {
+        let pitch_classes = |pitches: &[Pitch]| -> Vec<PitchClass> {
+            pitches.iter().copied().map(PitchClass::from).collect()
+        };
+        let c_major = pitch_classes(major_scale(C4).pitches());
+        let g_major = pitch_classes(major_scale(G4).pitches());
+        let d_dorian = pitch_classes(&major_mode(2, D4));
+
+        assert_eq!(
+            dedupe_by_pitch_class_set(&[
+                c_major.clone(),
+                g_major.clone(),
+                c_major.clone(),
+                d_dorian
+            ]),
+            vec![c_major, g_major]
+        );
+        assert!(dedupe_by_pitch_class_set(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_detect_key_major() {
+        assert_eq!(
+            detect_key(&[C4, D4, E4, F4, G4, E4, D4, C4]),
+            (PitchClass::from(C4), true)
+        );
+        assert_eq!(
+            detect_key(&[G4, B4, D5, FSHARP5, G5]),
+            (PitchClass::from(G4), true)
+        );
+    }
+
+    #[test]
+    fn test_detect_key_minor() {
+        assert_eq!(
+            detect_key(&[A3, C4, E4, D4, B3, A3]),
+            (PitchClass::from(A3), false)
+        );
+    }
+
+    #[test]
+    fn test_mode_brightness() {
+        let brightness = |mode: usize| {
+            let pitch_classes: Vec<PitchClass> = major_mode(mode, C4)
+                .into_iter()
+                .map(PitchClass::from)
+                .collect();
+            mode_brightness(&pitch_classes)
+        };
+
+        let (lydian, ionian, locrian) = (brightness(4), brightness(1), brightness(7));
+        assert!(lydian > ionian);
+        assert!(ionian > locrian);
+        assert_eq!((lydian, ionian, locrian), (1, 0, -5));
+        assert_eq!(mode_brightness(&[]), 0);
+    }
+
+    #[test]
+    fn test_invert_scale_about_tonic() {
+        assert_eq!(
+            invert_scale_about(major_scale(C4).pitches(), C4),
+            vec![C4, BFLAT3, AFLAT3, G3, F3, EFLAT3, DFLAT3, C3]
+        );
+        assert_eq!(
+            invert_scale_about(&[C4, E4, G4], E4),
+            vec![GSHARP4, E4, CSHARP4]
+        );
+    }
+
+    #[test]
+    fn test_invert_scale_about_drops_out_of_range() {
+        assert_eq!(invert_scale_about(&[C, C4, G9], C6), vec![C8, F2]);
+    }
+
+    #[test]
+    fn test_detect_scale_no_match() {
+        assert!(detect_scale(&[C4, CSHARP4, D4, DSHARP4]).is_empty());
+        assert!(detect_scale(&[]).is_empty());
+    }
+}
@@ -9,3 +9,4 @@
 use crate::core::{Interval, Step};
 
 pub use super::heptatonic::constants::*;
+pub use super::hexatonic::constants::*;
@@ -0,0 +1,21 @@
+use paste::paste;
+
+define_scale!(
+    HarmonicMinor,
+    [WHOLE, HALF, WHOLE, WHOLE, HALF, WHOLE_AND_HALF, HALF]
+);
+
+pub use harmonicminor::constants::*;
+pub use harmonicminor::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_harmonic_minor_scale() {
+        let scale = harmonicminor_scale(C4);
+        assert_eq!(scale.pitches(), &[C4, D4, EFLAT4, F4, G4, AFLAT4, B4, C5]);
+    }
+}
@@ -0,0 +1,76 @@
+use paste::paste;
+
+define_scale!(
+    MelodicMinor,
+    [WHOLE, HALF, WHOLE, WHOLE, WHOLE, WHOLE, HALF]
+);
+
+pub use melodicminor::constants::*;
+pub use melodicminor::*;
+
+use crate::core::Pitch;
+use crate::scales::naturalminor_scale;
+
+/// The melodic minor scale in both its traditional directions
+///
+/// Ascending, the melodic minor raises the sixth and seventh degrees of the natural minor.
+/// Descending, it traditionally reverts to the natural minor.
+pub struct MelodicMinorScale;
+
+impl MelodicMinorScale {
+    /// Returns the ascending form of the melodic minor scale
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The tonic of the scale
+    ///
+    /// # Returns
+    ///
+    /// The pitches from the tonic up to the octave, with raised sixth and seventh degrees
+    pub fn ascending(root: Pitch) -> Vec<Pitch> {
+        melodicminor_scale(root).pitches().to_vec()
+    }
+
+    /// Returns the descending form of the melodic minor scale
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The tonic of the scale
+    ///
+    /// # Returns
+    ///
+    /// The pitches of the natural minor scale from the octave down to the tonic
+    pub fn descending(root: Pitch) -> Vec<Pitch> {
+        let mut pitches = naturalminor_scale(root).pitches().to_vec();
+        pitches.reverse();
+        pitches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_melodic_minor_scale() {
+        let scale = melodicminor_scale(C4);
+        assert_eq!(scale.pitches(), &[C4, D4, EFLAT4, F4, G4, A4, B4, C5]);
+    }
+
+    #[test]
+    fn test_melodic_minor_ascending() {
+        let pitches = MelodicMinorScale::ascending(C4);
+        assert_eq!(pitches, vec![C4, D4, EFLAT4, F4, G4, A4, B4, C5]);
+        assert!(pitches.contains(&B4));
+        assert!(!pitches.contains(&BFLAT4));
+    }
+
+    #[test]
+    fn test_melodic_minor_descending() {
+        let pitches = MelodicMinorScale::descending(C4);
+        assert_eq!(pitches, vec![C5, BFLAT4, AFLAT4, G4, F4, EFLAT4, D4, C4]);
+        assert!(pitches.contains(&BFLAT4));
+        assert!(!pitches.contains(&B4));
+    }
+}
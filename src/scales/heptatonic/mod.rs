@@ -3,18 +3,26 @@
 //! This module provides implementations for 7-note scales, including:
 //! - Major scale
 //! - Natural minor scale
+//! - Harmonic minor scale
+//! - Melodic minor scale
 //!
 //! Each scale is implemented with its own module containing the scale definition
 //! and related constants.
 
+pub mod harmonicminor;
 pub mod major;
+pub mod melodicminor;
 pub mod naturalminor;
 
+pub use harmonicminor::*;
 pub use major::*;
+pub use melodicminor::*;
 pub use naturalminor::*;
 
 pub mod constants {
+    pub use super::harmonicminor::constants::*;
     pub use super::major::constants::*;
+    pub use super::melodicminor::constants::*;
     pub use super::naturalminor::constants::*;
 }
 
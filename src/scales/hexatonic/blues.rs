@@ -0,0 +1,32 @@
+use paste::paste;
+
+define_scale!(
+    Blues,
+    [WHOLE_AND_HALF, WHOLE, HALF, HALF, WHOLE_AND_HALF, WHOLE]
+);
+
+pub use blues::constants::*;
+pub use blues::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::scales::ScaleQuality;
+
+    #[test]
+    fn test_blues_scale() {
+        let scale = blues_scale(C4);
+        assert_eq!(scale.pitches(), &[C4, EFLAT4, F4, FSHARP4, G4, BFLAT4, C5]);
+    }
+
+    #[test]
+    fn test_blues_scale_lengths() {
+        assert_eq!(BluesQuality::STEPS_LENGTH, 6);
+        assert_eq!(BluesQuality::PITCHES_LENGTH, 7);
+        assert_eq!(
+            blues_scale_in_steps().steps(),
+            &[WHOLE_AND_HALF, WHOLE, HALF, HALF, WHOLE_AND_HALF, WHOLE]
+        );
+    }
+}
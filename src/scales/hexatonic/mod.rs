@@ -0,0 +1,15 @@
+//! Hexatonic scales module
+//!
+//! This module provides implementations for 6-note scales, including:
+//! - Blues scale
+//!
+//! Each scale is implemented with its own module containing the scale definition
+//! and related constants.
+
+pub mod blues;
+
+pub use blues::*;
+
+pub mod constants {
+    pub use super::blues::constants::*;
+}
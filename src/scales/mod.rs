@@ -11,8 +11,9 @@
 //! - Steps: [WHOLE, WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF]
 //! - Pitches: [C4, D4, E4, F4, G4, A4, B4, C5] (when starting from C4)
 //!
-//! The module provides implementations for common scales through the heptatonic module,
-//! which includes 7-note scales like major and natural minor. The module allows creating
+//! The module provides implementations for common scales through the hexatonic module,
+//! which includes 6-note scales like the blues scale, and the heptatonic module, which
+//! includes 7-note scales like major and natural minor. The module allows creating
 //! custom scales by implementing the appropriate traits.
 
 use crate::{Interval, Pitch, Step};
@@ -38,29 +39,143 @@ pub trait ScaleQuality: Sized {
     }
 }
 
-/// Generic scale type with additional validation
+/// Error returned when a scale cannot be constructed
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScaleError {
+    /// The quality's step pattern does not have the quality's declared length
+    InvalidPattern,
+    /// The number of elements matches neither the quality's step count nor its pitch count
+    WrongLength {
+        /// The number of elements the scale was constructed with
+        length: usize,
+    },
+}
+
+impl std::fmt::Display for ScaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidPattern => write!(f, "invalid scale pattern"),
+            Self::WrongLength { length } => write!(f, "invalid scale length: {}", length),
+        }
+    }
+}
+
+impl std::error::Error for ScaleError {}
+
+/// Error returned when a step pattern string cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// The pattern contains a character that is not `W`, `H` or a digit
+    InvalidChar {
+        /// The offending character
+        character: char,
+    },
+}
+
+impl std::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidChar { character } => {
+                write!(f, "invalid character in scale pattern: {:?}", character)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// Compile-time check that a scale has at least one element
+struct NonEmpty<const N: usize>;
+
+impl<const N: usize> NonEmpty<N> {
+    const CHECK: () = assert!(N > 0, "A scale must have at least one element");
+}
+
+/// Generic scale type with additional validation
+#[derive(Clone, PartialEq, Eq)]
 pub struct Scale<Q: ScaleQuality, T, const N: usize> {
     items: [T; N],
     _quality: PhantomData<Q>,
 }
 
+impl<Q: ScaleQuality, T: std::fmt::Debug, const N: usize> std::fmt::Debug for Scale<Q, T, N> {
+    /// Formats the scale as its quality name followed by its elements, e.g.
+    /// `MajorScale[Pitch(60), Pitch(62), ...]`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quality = std::any::type_name::<Q>();
+        let quality = quality.split('<').next().unwrap_or(quality);
+        let quality = quality.rsplit("::").next().unwrap_or(quality);
+        let name = quality.strip_suffix("Quality").unwrap_or(quality);
+
+        write!(f, "{}Scale", name)?;
+        f.debug_list().entries(self.items.iter()).finish()
+    }
+}
+
+impl<Q: ScaleQuality, T: std::fmt::Display, const N: usize> std::fmt::Display for Scale<Q, T, N> {
+    /// Formats the elements of the scale comma-separated in brackets, e.g.
+    /// `[C4, D4, E4, ...]` for a scale of pitches
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
 impl<Q: ScaleQuality, T, const N: usize> Scale<Q, T, N>
 where
     T: std::fmt::Debug,
 {
     /// Creates a new scale with validation
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale fails validation, see [`Scale::try_new`]
     pub fn new(items: [T; N]) -> Self {
-        assert!(
-            Q::validate_steps(Q::STEPS_PATTERN.as_ref()),
-            "Invalid scale pattern. pattern: {:?}",
-            Q::STEPS_PATTERN.as_ref()
-        );
+        Self::try_new(items).unwrap()
+    }
 
-        Self {
+    /// Creates a new scale, returning an error if validation fails
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The elements of the scale
+    ///
+    /// # Returns
+    ///
+    /// The scale, or a `ScaleError` if the quality's step pattern is invalid or the
+    /// number of elements matches neither the step count nor the pitch count of the quality
+    ///
+    /// A scale with no elements is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use no_surprises::prelude::*;
+    /// use no_surprises::scales::{MajorQuality, Scale};
+    ///
+    /// let scale = Scale::<MajorQuality, Pitch, 0>::try_new([]);
+    /// ```
+    pub fn try_new(items: [T; N]) -> Result<Self, ScaleError> {
+        let () = NonEmpty::<N>::CHECK;
+
+        if !Q::validate_steps(Q::STEPS_PATTERN.as_ref()) {
+            return Err(ScaleError::InvalidPattern);
+        }
+
+        if N != Q::STEPS_LENGTH && N != Q::PITCHES_LENGTH {
+            return Err(ScaleError::WrongLength { length: N });
+        }
+
+        Ok(Self {
             items,
             _quality: PhantomData,
-        }
+        })
     }
 
     /// Returns a reference to the elements of the scale
@@ -74,6 +189,307 @@ where
     }
 }
 
+impl<Q: ScaleQuality, T, const N: usize> Scale<Q, T, N> {
+    /// Returns the number of elements in the scale
+    ///
+    /// # Returns
+    ///
+    /// The length `N` of the scale
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the scale has no elements
+    ///
+    /// # Returns
+    ///
+    /// `true` if `N` is zero, `false` otherwise
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Applies a function to every element of the scale, keeping its quality and length
+    ///
+    /// The resulting scale is not validated, since the mapped elements need not be
+    /// musical types.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function applied to each element
+    ///
+    /// # Returns
+    ///
+    /// A scale of the same quality holding the mapped elements
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Scale<Q, U, N> {
+        Scale {
+            items: std::array::from_fn(|i| f(&self.items[i])),
+            _quality: PhantomData,
+        }
+    }
+
+    /// Applies a fallible function to every element of the scale, keeping its quality and
+    /// length
+    ///
+    /// Mapping stops at the first element for which `f` fails. As with [`Scale::map`],
+    /// the resulting scale is not validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function applied to each element
+    ///
+    /// # Returns
+    ///
+    /// A scale of the same quality holding the mapped elements, or the first error
+    /// returned by `f`
+    pub fn try_map<U, E>(&self, f: impl Fn(&T) -> Result<U, E>) -> Result<Scale<Q, U, N>, E> {
+        let items: Vec<U> = self.items.iter().map(f).collect::<Result<_, _>>()?;
+        let Ok(items) = <[U; N]>::try_from(items) else {
+            unreachable!("mapping a scale keeps its length");
+        };
+
+        Ok(Scale {
+            items,
+            _quality: PhantomData,
+        })
+    }
+
+    /// Copies the elements of the scale into a vector
+    ///
+    /// # Returns
+    ///
+    /// A vector holding the elements of the scale, in order
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.items.to_vec()
+    }
+}
+
+impl<Q: ScaleQuality, T, const N: usize> From<Scale<Q, T, N>> for Vec<T> {
+    /// Converts a scale into a vector of its elements
+    fn from(scale: Scale<Q, T, N>) -> Self {
+        Vec::from(scale.items)
+    }
+}
+
+/// Returns the number of semitones of each degree above the tonic, starting with the
+/// tonic itself at 0, by accumulating a step pattern.
+pub(crate) fn degree_semitones(steps: &[Step]) -> impl Iterator<Item = u8> + '_ {
+    std::iter::once(0).chain(steps.iter().scan(0, |semitones, step| {
+        *semitones += step.semitones();
+        Some(*semitones)
+    }))
+}
+
+/// Uniform conversions between scale representations
+///
+/// Every scale, whether it stores steps, intervals or pitches, can be viewed in the
+/// other two representations. Generic code can rely on this trait without knowing how
+/// a scale is stored. Conversions borrow the scale and return vectors, so the scale
+/// stays available to the caller.
+pub trait ScaleConvert {
+    /// Returns the steps between consecutive degrees of the scale
+    fn as_steps(&self) -> Vec<Step>;
+
+    /// Returns the intervals of the scale degrees above the tonic, excluding the tonic
+    fn as_intervals(&self) -> Vec<Interval>;
+
+    /// Returns the pitches of the scale starting from a root
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The pitch of the tonic
+    fn as_pitches(&self, root: Pitch) -> Vec<Pitch>;
+}
+
+impl<Q: ScaleQuality, const N: usize> ScaleConvert for Scale<Q, Step, N> {
+    fn as_steps(&self) -> Vec<Step> {
+        self.items.to_vec()
+    }
+
+    fn as_intervals(&self) -> Vec<Interval> {
+        degree_semitones(&self.items)
+            .skip(1)
+            .map(Interval::new)
+            .collect()
+    }
+
+    fn as_pitches(&self, root: Pitch) -> Vec<Pitch> {
+        degree_semitones(&self.items)
+            .map(|semitones| root + Step::new(semitones))
+            .collect()
+    }
+}
+
+impl<Q: ScaleQuality, const N: usize> ScaleConvert for Scale<Q, Interval, N> {
+    fn as_steps(&self) -> Vec<Step> {
+        std::iter::once(Interval::new(0))
+            .chain(self.items.iter().copied())
+            .collect::<Vec<_>>()
+            .windows(2)
+            .map(|intervals| Step::new(intervals[1].semitones() - intervals[0].semitones()))
+            .collect()
+    }
+
+    fn as_intervals(&self) -> Vec<Interval> {
+        self.items.to_vec()
+    }
+
+    fn as_pitches(&self, root: Pitch) -> Vec<Pitch> {
+        std::iter::once(root)
+            .chain(
+                self.items
+                    .iter()
+                    .map(|interval| root + Step::from(*interval)),
+            )
+            .collect()
+    }
+}
+
+impl<Q: ScaleQuality, const N: usize> ScaleConvert for Scale<Q, Pitch, N> {
+    fn as_steps(&self) -> Vec<Step> {
+        self.items
+            .windows(2)
+            .map(|pitches| pitches[1] - pitches[0])
+            .collect()
+    }
+
+    fn as_intervals(&self) -> Vec<Interval> {
+        self.items
+            .iter()
+            .skip(1)
+            .map(|pitch| Interval::from(*pitch - self.items[0]))
+            .collect()
+    }
+
+    fn as_pitches(&self, root: Pitch) -> Vec<Pitch> {
+        degree_semitones(&self.as_steps())
+            .map(|semitones| root + Step::new(semitones))
+            .collect()
+    }
+}
+
+/// A scale whose step pattern is only known at runtime
+///
+/// Unlike `Scale`, the length and quality of a `DynScale` are not part of its type, so
+/// scales of different sizes can be stored together, e.g. in a library of scales loaded
+/// at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynScale {
+    name: String,
+    root: Pitch,
+    steps: Vec<Step>,
+}
+
+impl DynScale {
+    /// Creates a new runtime scale
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the scale
+    /// * `root` - The pitch of the tonic
+    /// * `steps` - The steps between consecutive degrees of the scale
+    ///
+    /// # Returns
+    ///
+    /// A new DynScale
+    pub fn new(name: impl Into<String>, root: Pitch, steps: Vec<Step>) -> Self {
+        Self {
+            name: name.into(),
+            root,
+            steps,
+        }
+    }
+
+    /// Returns the name of the scale
+    ///
+    /// # Returns
+    ///
+    /// The name the scale was created with
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the pitch of the tonic
+    ///
+    /// # Returns
+    ///
+    /// The root of the scale
+    #[inline]
+    pub const fn root(&self) -> Pitch {
+        self.root
+    }
+
+    /// Returns the steps between consecutive degrees of the scale
+    ///
+    /// # Returns
+    ///
+    /// A slice of steps
+    #[inline]
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Returns the pitches of the scale starting from its root
+    ///
+    /// # Returns
+    ///
+    /// The root followed by one pitch per step
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.as_pitches(self.root)
+    }
+}
+
+impl ScaleConvert for DynScale {
+    fn as_steps(&self) -> Vec<Step> {
+        self.steps.clone()
+    }
+
+    fn as_intervals(&self) -> Vec<Interval> {
+        degree_semitones(&self.steps)
+            .skip(1)
+            .map(Interval::new)
+            .collect()
+    }
+
+    fn as_pitches(&self, root: Pitch) -> Vec<Pitch> {
+        degree_semitones(&self.steps)
+            .map(|semitones| root + Step::new(semitones))
+            .collect()
+    }
+}
+
+/// Parses a step pattern written in W/H shorthand
+///
+/// This is the inverse of `ScaleInSteps::pattern_string`: `W` is a whole step, `H` is a
+/// half step and a digit is a step of that many semitones, e.g. `3` for an augmented
+/// second.
+///
+/// # Arguments
+///
+/// * `s` - The pattern string, e.g. `"WWHWWWH"` for the major scale
+///
+/// # Returns
+///
+/// The steps of the pattern, or `PatternError::InvalidChar` for the first character
+/// that is not `W`, `H` or a digit
+pub fn steps_from_pattern(s: &str) -> Result<Vec<Step>, PatternError> {
+    s.chars()
+        .map(|character| match character {
+            'W' => Ok(crate::WHOLE),
+            'H' => Ok(crate::HALF),
+            _ => character
+                .to_digit(10)
+                .map(|semitones| Step::new(semitones as u8))
+                .ok_or(PatternError::InvalidChar { character }),
+        })
+        .collect()
+}
+
 use paste::paste;
 
 /// Macro for defining the quality struct and its implementation
@@ -84,7 +500,7 @@ macro_rules! define_scale_quality {
             pub struct [<$name Quality>];
 
             impl ScaleQuality for [<$name Quality>] {
-                const STEPS_LENGTH: usize = 7;
+                const STEPS_LENGTH: usize = $steps.len();
                 type Pattern = [Step; Self::STEPS_LENGTH];
                 const STEPS_PATTERN: Self::Pattern = $steps;
             }
@@ -120,7 +536,12 @@ macro_rules! impl_scale_steps {
 
                 #[inline]
                 pub fn to_pitches(&self) -> [<$name ScalePitches>] {
-                    [<$name ScalePitches>]::new(self.items.into_pitches(C4))
+                    self.to_pitches_from(C4)
+                }
+
+                #[inline]
+                pub fn to_pitches_from(&self, root: Pitch) -> [<$name ScalePitches>] {
+                    [<$name ScalePitches>]::new(self.items.into_pitches(root))
                 }
             }
         }
@@ -144,7 +565,12 @@ macro_rules! impl_scale_intervals {
 
                 #[inline]
                 pub fn to_pitches(&self) -> [<$name ScalePitches>] {
-                    [<$name ScalePitches>]::new(self.items.into_pitches(C4))
+                    self.to_pitches_from(C4)
+                }
+
+                #[inline]
+                pub fn to_pitches_from(&self, root: Pitch) -> [<$name ScalePitches>] {
+                    [<$name ScalePitches>]::new(self.items.into_pitches(root))
                 }
             }
         }
@@ -274,8 +700,235 @@ macro_rules! define_scale {
 
 pub mod constants;
 
+// Operations shared by every scale represented as pitches
+mod scale_pitches;
+
+pub use scale_pitches::*;
+
+// Operations shared by every scale represented as steps
+mod scale_steps;
+
+pub use scale_steps::*;
+
+// Modes of the major scale
+mod modes;
+
+pub use modes::*;
+
+// Suggesting scales for a sequence of pitches
+mod analysis;
+
+pub use analysis::*;
+
+// Module for hexatonic scales (6-note scales)
+mod hexatonic;
+
 // Module for heptatonic scales (7-note scales)
 mod heptatonic;
 
+// Re-export hexatonic scales
+pub use hexatonic::*;
+
 // Re-export heptatonic scales
 pub use heptatonic::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct BrokenQuality;
+
+    impl ScaleQuality for BrokenQuality {
+        const STEPS_LENGTH: usize = 3;
+        type Pattern = [Step; 2];
+        const STEPS_PATTERN: Self::Pattern = [WHOLE, HALF];
+    }
+
+    #[test]
+    fn test_try_new() {
+        let scale = Scale::<MajorQuality, Step, 7>::try_new(MAJOR_SCALE_STEPS);
+        assert_eq!(scale.unwrap().items(), &MAJOR_SCALE_STEPS);
+    }
+
+    #[test]
+    fn test_try_new_invalid_pattern() {
+        let scale = Scale::<BrokenQuality, Step, 3>::try_new([WHOLE, HALF, WHOLE]);
+        assert_eq!(scale, Err(ScaleError::InvalidPattern));
+    }
+
+    #[test]
+    fn test_try_new_wrong_length() {
+        let scale = Scale::<MajorQuality, Step, 3>::try_new([WHOLE, WHOLE, HALF]);
+        assert_eq!(scale, Err(ScaleError::WrongLength { length: 3 }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_panics_on_invalid_pattern() {
+        Scale::<BrokenQuality, Step, 3>::new([WHOLE, HALF, WHOLE]);
+    }
+
+    #[test]
+    fn test_steps_from_pattern() {
+        assert_eq!(
+            steps_from_pattern("WWHWWWH"),
+            Ok(vec![WHOLE, WHOLE, HALF, WHOLE, WHOLE, WHOLE, HALF])
+        );
+        assert_eq!(
+            steps_from_pattern("WHWWH3H"),
+            Ok(HarmonicMinorQuality::STEPS_PATTERN.to_vec())
+        );
+        assert_eq!(steps_from_pattern(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_steps_from_pattern_invalid_char() {
+        assert_eq!(
+            steps_from_pattern("WWXH"),
+            Err(PatternError::InvalidChar { character: 'X' })
+        );
+        assert_eq!(
+            steps_from_pattern("wwh"),
+            Err(PatternError::InvalidChar { character: 'w' })
+        );
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(major_scale(C4).len(), 8);
+        assert_eq!(major_scale_in_steps().len(), 7);
+        assert_eq!(blues_scale(C4).len(), 7);
+        assert!(!major_scale(C4).is_empty());
+    }
+
+    #[test]
+    fn test_map_to_midi_numbers() {
+        let scale = major_scale(C4);
+        let midi: Scale<MajorQuality, u8, 8> = scale.map(|pitch| pitch.semitones());
+        assert_eq!(midi.items(), &[60, 62, 64, 65, 67, 69, 71, 72]);
+    }
+
+    #[test]
+    fn test_map_transpose() {
+        let scale = major_scale(C4).map(|pitch| *pitch + WHOLE);
+        assert_eq!(scale, major_scale(D4));
+    }
+
+    #[test]
+    fn test_try_map() {
+        let up_an_octave = |pitch: &Pitch| pitch.shift_octave(1).ok_or(*pitch);
+
+        assert_eq!(major_scale(C4).try_map(up_an_octave), Ok(major_scale(C5)));
+        assert_eq!(major_scale(C8).try_map(up_an_octave), Err(A8));
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let pitches: Vec<Pitch> = major_scale(C4).into();
+        assert_eq!(pitches, vec![C4, D4, E4, F4, G4, A4, B4, C5]);
+        assert_eq!(major_scale_in_steps().to_vec(), MAJOR_SCALE_STEPS.to_vec());
+    }
+
+    fn convert_all<S: ScaleConvert>(scale: &S) -> (Vec<Step>, Vec<Interval>, Vec<Pitch>) {
+        (scale.as_steps(), scale.as_intervals(), scale.as_pitches(D4))
+    }
+
+    #[test]
+    fn test_scale_convert_is_uniform() {
+        let expected = (
+            MAJOR_SCALE_STEPS.to_vec(),
+            vec![
+                MAJOR_SECOND,
+                MAJOR_THIRD,
+                PERFECT_FOURTH,
+                PERFECT_FIFTH,
+                MAJOR_SIXTH,
+                MAJOR_SEVENTH,
+                PERFECT_OCTAVE,
+            ],
+            major_scale(D4).to_vec(),
+        );
+
+        assert_eq!(convert_all(&major_scale_in_steps()), expected);
+        assert_eq!(convert_all(&major_scale_in_intervals()), expected);
+        assert_eq!(convert_all(&major_scale(C4)), expected);
+    }
+
+    #[test]
+    fn test_conversions_borrow_the_scale() {
+        let steps = major_scale_in_steps();
+        let intervals = steps.to_intervals();
+        let pitches = steps.to_pitches();
+        assert_eq!(steps.steps(), &MAJOR_SCALE_STEPS);
+
+        assert_eq!(intervals.to_steps(), steps);
+        assert_eq!(intervals.to_pitches(), pitches);
+        assert_eq!(intervals.intervals().len(), 7);
+
+        assert_eq!(pitches.to_steps(), steps);
+        assert_eq!(pitches.to_intervals(), intervals);
+        assert_eq!(pitches.as_steps(), steps.to_vec());
+        assert_eq!(pitches.pitches(), &[C4, D4, E4, F4, G4, A4, B4, C5]);
+    }
+
+    #[test]
+    fn test_dyn_scales_in_a_collection() {
+        let mut library = vec![
+            DynScale::new("major", C4, MAJOR_SCALE_STEPS.to_vec()),
+            DynScale::new("blues", A3, BLUES_SCALE_STEPS.to_vec()),
+        ];
+        library.push(DynScale::new(
+            "major pentatonic",
+            G4,
+            vec![WHOLE, WHOLE, WHOLE_AND_HALF, WHOLE, WHOLE_AND_HALF],
+        ));
+
+        let pitches: Vec<Vec<Pitch>> = library.iter().map(DynScale::pitches).collect();
+        assert_eq!(pitches[0], major_scale(C4).to_vec());
+        assert_eq!(pitches[1], blues_scale(A3).to_vec());
+        assert_eq!(pitches[2], vec![G4, A4, B4, D5, E5, G5]);
+        assert_eq!(library[2].name(), "major pentatonic");
+        assert_eq!(
+            library[1].as_intervals(),
+            blues_scale_in_intervals().to_vec()
+        );
+    }
+
+    #[test]
+    fn test_to_pitches_from_root() {
+        assert_eq!(major_scale_in_steps().to_pitches_from(G4), major_scale(G4));
+        assert_eq!(
+            major_scale_in_intervals().to_pitches_from(G4),
+            major_scale(G4)
+        );
+        assert_eq!(major_scale_in_steps().to_pitches(), major_scale(C4));
+    }
+
+    #[test]
+    fn test_display_format() {
+        assert_eq!(
+            major_scale(C4).to_string(),
+            "[C4, D4, E4, F4, G4, A4, B4, C5]"
+        );
+        assert_eq!(
+            major_scale(D4).to_string(),
+            "[D4, E4, F#4, G4, A4, B4, C#5, D5]"
+        );
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let formatted = format!("{:?}", major_scale(C4));
+        assert!(formatted.starts_with("MajorScale["));
+        assert!(formatted.contains("Pitch(60), Pitch(62)"));
+        assert!(!formatted.contains("PhantomData"));
+        assert!(!formatted.contains("items"));
+
+        assert_eq!(
+            format!("{:?}", blues_scale_in_steps()),
+            "BluesScale[Step(3), Step(2), Step(1), Step(1), Step(3), Step(2)]"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! Modes of the major scale
+//!
+//! A mode is obtained by starting a scale's step pattern on a different degree. The seven
+//! modes of the major scale are, by starting degree: Ionian (1), Dorian (2), Phrygian (3),
+//! Lydian (4), Mixolydian (5), Aeolian (6) and Locrian (7).
+
+use crate::core::{IntoPitches, Pitch};
+
+use super::{MAJOR_SCALE_STEPS, MajorQuality, ScaleQuality};
+
+/// Returns the pitches of a mode of the major scale.
+///
+/// The mode's step pattern is the major pattern rotated to start on the given degree,
+/// and its pitches start from `root`. Degree 1 is Ionian, 2 is Dorian, and so on up to
+/// 7 for Locrian.
+///
+/// # Arguments
+///
+/// * `degree` - The degree of the major scale the mode starts on (1-7)
+/// * `root` - The first pitch of the mode
+///
+/// # Returns
+///
+/// The pitches of the mode, from `root` up to the octave above it
+///
+/// # Panics
+///
+/// Panics if `degree` is not in the range 1-7
+pub fn major_mode(degree: usize, root: Pitch) -> Vec<Pitch> {
+    assert!(
+        (1..=MAJOR_SCALE_STEPS.len()).contains(&degree),
+        "Invalid mode degree. degree: {}",
+        degree
+    );
+
+    let mut steps = MAJOR_SCALE_STEPS;
+    steps.rotate_left(degree - 1);
+
+    let pitches: [Pitch; MajorQuality::PITCHES_LENGTH] = steps.into_pitches(root);
+    pitches.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ionian() {
+        assert_eq!(major_mode(1, C4), vec![C4, D4, E4, F4, G4, A4, B4, C5]);
+    }
+
+    #[test]
+    fn test_dorian() {
+        assert_eq!(major_mode(2, D4), vec![D4, E4, F4, G4, A4, B4, C5, D5]);
+    }
+
+    #[test]
+    fn test_aeolian() {
+        assert_eq!(major_mode(6, A4), vec![A4, B4, C5, D5, E5, F5, G5, A5]);
+    }
+
+    #[test]
+    fn test_locrian_on_another_root() {
+        assert_eq!(
+            major_mode(7, C4),
+            vec![C4, DFLAT4, EFLAT4, F4, GFLAT4, AFLAT4, BFLAT4, C5]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_degree() {
+        major_mode(8, C4);
+    }
+}
@@ -0,0 +1,1607 @@
+//! Operations on scales represented as pitches
+//!
+//! This module provides the `ScaleInPitches` alias for any scale stored as pitches, along
+//! with operations that apply to every such scale regardless of its quality, such as
+//! exporting the scale to text-based notation formats (ABC and LilyPond).
+
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+use super::{Scale, ScaleQuality};
+use crate::core::{
+    Chord, ChordBuilder, Duration, Interval, IntoPitches, IntoSteps, Note, Pitch, PitchClass,
+    PitchClassSet, PitchRange, SEMITONES_IN_OCTAVE, Step, TriadQuality, neighboring_keys,
+    triad_quality,
+};
+use crate::{A4_FREQUENCY_HZ, C, MAJOR_THIRD, PERFECT_FIFTH, PERFECT_FOURTH};
+
+/// Pitch classes that fall on the black keys of a piano.
+const BLACK_KEYS: [u8; 5] = [1, 3, 6, 8, 10];
+
+/// Number of semitones in a tritone.
+const TRITONE: u8 = SEMITONES_IN_OCTAVE / 2;
+
+/// Roman numerals of the degrees of a heptatonic scale.
+const ROMAN_NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+/// A scale of any quality represented by its pitches
+pub type ScaleInPitches<Q, const N: usize> = Scale<Q, Pitch, N>;
+
+/// The functional name of a degree of a heptatonic scale
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DegreeName {
+    /// The first degree
+    Tonic,
+    /// The second degree
+    Supertonic,
+    /// The third degree
+    Mediant,
+    /// The fourth degree
+    Subdominant,
+    /// The fifth degree
+    Dominant,
+    /// The sixth degree
+    Submediant,
+    /// The seventh degree, a half step below the tonic
+    LeadingTone,
+    /// The seventh degree, a whole step below the tonic
+    Subtonic,
+}
+
+/// Letter names of the natural notes, starting from C
+const LETTERS: [char; 7] = ['C', 'D', 'E', 'F', 'G', 'A', 'B'];
+
+/// Pitch classes of the natural notes, in the same order as `LETTERS`
+const NATURALS: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Advances a SplitMix64 generator and returns its next pseudo-random value.
+///
+/// SplitMix64 is tiny and works with any seed, including 0, which is all the seeded
+/// melody generation needs without pulling in a random number crate.
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A pitch written as a letter name, an accidental and an octave number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Spelling {
+    /// Index of the letter name in `LETTERS`
+    letter: usize,
+    /// Number of sharps (positive) or flats (negative)
+    accidental: i8,
+    /// Octave number of the letter name, where middle C is in octave 4
+    octave: i8,
+}
+
+impl Spelling {
+    /// Spells a pitch with the given letter name, if it needs at most a double accidental
+    fn with_letter(pitch: Pitch, letter: usize) -> Option<Self> {
+        let semitones = i16::from(pitch.semitones());
+        let octave = i16::from(SEMITONES_IN_OCTAVE);
+
+        let mut accidental = semitones % octave - i16::from(NATURALS[letter]);
+        if accidental > octave / 2 {
+            accidental -= octave;
+        } else if accidental < -octave / 2 {
+            accidental += octave;
+        }
+
+        if accidental.abs() > 2 {
+            return None;
+        }
+
+        Some(Self {
+            letter,
+            accidental: accidental as i8,
+            octave: ((semitones - accidental).div_euclid(octave) - 1) as i8,
+        })
+    }
+
+    /// Spells a pitch on its own, using its natural letter name or a sharp
+    fn sharp(pitch: Pitch) -> Self {
+        let pitch_class = pitch.semitones() % SEMITONES_IN_OCTAVE;
+        let letter = NATURALS
+            .iter()
+            .rposition(|natural| *natural <= pitch_class)
+            .unwrap_or_default();
+
+        Self::with_letter(pitch, letter).expect("a natural or sharp spelling always exists")
+    }
+}
+
+/// Spells every pitch of a scale.
+///
+/// Heptatonic scales use every letter name once, starting from the tonic spelling that
+/// needs the fewest accidentals (preferring sharps over flats on a tie). Other scales
+/// spell each pitch on its own, with sharps for the black keys.
+fn spell(pitches: &[Pitch]) -> Vec<Spelling> {
+    if pitches.len() != LETTERS.len() + 1 {
+        return pitches
+            .iter()
+            .map(|pitch| Spelling::sharp(*pitch))
+            .collect();
+    }
+
+    let spell_from = |tonic: usize| -> Option<Vec<Spelling>> {
+        pitches
+            .iter()
+            .enumerate()
+            .map(|(degree, pitch)| Spelling::with_letter(*pitch, (tonic + degree) % LETTERS.len()))
+            .collect()
+    };
+
+    let cost = |spellings: &Vec<Spelling>| {
+        let total: i32 = spellings
+            .iter()
+            .map(|s| i32::from(s.accidental.abs()))
+            .sum();
+        let flats = spellings.iter().filter(|s| s.accidental < 0).count();
+        (total, flats)
+    };
+
+    (0..LETTERS.len())
+        .filter_map(spell_from)
+        .min_by_key(cost)
+        .unwrap_or_else(|| {
+            pitches
+                .iter()
+                .map(|pitch| Spelling::sharp(*pitch))
+                .collect()
+        })
+}
+
+/// A scale in pitches together with its precomputed pitch class mask.
+///
+/// The mask is computed once when the wrapper is built, so membership queries are a
+/// single bit test. Use it when the same scale answers many lookups, such as a keyboard
+/// highlighting the keys of the current key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaskedScale<Q: ScaleQuality, const N: usize> {
+    scale: ScaleInPitches<Q, N>,
+    mask: u16,
+}
+
+impl<Q: ScaleQuality, const N: usize> MaskedScale<Q, N> {
+    /// Wraps a scale and computes its pitch class mask.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale to wrap
+    ///
+    /// # Returns
+    ///
+    /// The scale with its mask cached
+    pub fn new(scale: ScaleInPitches<Q, N>) -> Self {
+        let mask = scale.pitch_class_mask();
+        Self { scale, mask }
+    }
+
+    /// Returns the wrapped scale.
+    pub fn scale(&self) -> &ScaleInPitches<Q, N> {
+        &self.scale
+    }
+
+    /// Returns the cached 12-bit pitch class mask, where bit 0 stands for C.
+    pub fn pitch_class_mask(&self) -> u16 {
+        self.mask
+    }
+
+    /// Returns true if a pitch class is in the scale, using the cached mask.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch_class` - The pitch class to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if one of the scale's pitches has the pitch class, `false` otherwise
+    pub fn contains_pitch_class(&self, pitch_class: PitchClass) -> bool {
+        self.mask & (1 << pitch_class.semitones()) != 0
+    }
+}
+
+impl<Q: ScaleQuality, const N: usize> From<ScaleInPitches<Q, N>> for MaskedScale<Q, N> {
+    fn from(scale: ScaleInPitches<Q, N>) -> Self {
+        Self::new(scale)
+    }
+}
+
+impl<Q: ScaleQuality, const N: usize> ScaleInPitches<Q, N> {
+    /// Returns the pitch classes of the scale as a 12-bit membership mask.
+    ///
+    /// Bit 0 stands for C and bit 11 for B. The mask is computed from the pitches on each
+    /// call; wrap the scale in a [`MaskedScale`] to compute it once and reuse it.
+    ///
+    /// # Returns
+    ///
+    /// The mask with one bit set per pitch class of the scale
+    pub fn pitch_class_mask(&self) -> u16 {
+        PitchClassSet::from(self).bits()
+    }
+
+    /// Returns true if a pitch class is in the scale.
+    ///
+    /// The pitches are scanned directly, without building a mask. For many lookups
+    /// against the same scale, use [`MaskedScale::contains_pitch_class`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch_class` - The pitch class to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if one of the scale's pitches has the pitch class, `false` otherwise
+    pub fn contains_pitch_class(&self, pitch_class: PitchClass) -> bool {
+        self.items()
+            .iter()
+            .any(|pitch| PitchClass::from(*pitch) == pitch_class)
+    }
+
+    /// Snaps a pitch to the nearest pitch in the scale.
+    ///
+    /// Scale membership is decided by pitch class, so the scale is treated as repeating in
+    /// every octave and the snapped pitch stays next to the given one. When two scale
+    /// pitches are equally close, the higher one is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to snap
+    ///
+    /// # Returns
+    ///
+    /// The closest pitch whose pitch class is in the scale, or `pitch` itself if it is
+    /// already in the scale
+    pub fn snap(&self, pitch: Pitch) -> Pitch {
+        let pitch_classes = PitchClassSet::from(self);
+        (0..=SEMITONES_IN_OCTAVE / 2)
+            .flat_map(|distance| {
+                [
+                    pitch.semitones().checked_add(distance),
+                    pitch.semitones().checked_sub(distance),
+                ]
+            })
+            .flatten()
+            .map(Pitch::new)
+            .find(|candidate| pitch_classes.contains(PitchClass::from(*candidate)))
+            .unwrap_or(pitch)
+    }
+
+    /// Snaps a frequency to the nearest pitch in the scale.
+    ///
+    /// The frequency is first rounded to the nearest equal-tempered pitch, clamped to the
+    /// MIDI range, and that pitch is then snapped as by [`Scale::snap`].
+    ///
+    /// # Arguments
+    ///
+    /// * `freq_hz` - The frequency in Hz, with A4 tuned to 440 Hz
+    ///
+    /// # Returns
+    ///
+    /// The closest pitch whose pitch class is in the scale
+    pub fn snap_frequency(&self, freq_hz: f64) -> Pitch {
+        let pitch = Pitch::from_frequency_hz(freq_hz).unwrap_or(if freq_hz < A4_FREQUENCY_HZ {
+            C
+        } else {
+            Pitch::MAX
+        });
+        self.snap(pitch)
+    }
+
+    /// Lists every pitch of the scale within a range, repeating the scale in every octave.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The lowest pitch of the range, inclusive
+    /// * `high` - The highest pitch of the range, inclusive
+    ///
+    /// # Returns
+    ///
+    /// The pitches in the range whose pitch class is in the scale, in ascending order
+    pub fn pitches_in_range(&self, low: Pitch, high: Pitch) -> Vec<Pitch> {
+        let pitch_classes = PitchClassSet::from(self);
+        (low.semitones()..=high.semitones())
+            .map(Pitch::new)
+            .filter(|pitch| pitch_classes.contains(PitchClass::from(*pitch)))
+            .collect()
+    }
+
+    /// Transposes every pitch of the scale by the same step, keeping its quality.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - The step to transpose the scale up by
+    ///
+    /// # Returns
+    ///
+    /// The scale of the same quality starting `step` higher
+    pub fn transpose(&self, step: Step) -> Self {
+        self.map(|pitch| *pitch + step)
+    }
+
+    /// Returns the scale of the same quality built a perfect fifth higher.
+    ///
+    /// # Returns
+    ///
+    /// The dominant key, e.g. G major for C major
+    pub fn dominant_key(&self) -> Self {
+        self.transpose(Step::from(PERFECT_FIFTH))
+    }
+
+    /// Returns the scale of the same quality built a perfect fourth higher.
+    ///
+    /// # Returns
+    ///
+    /// The subdominant key, e.g. F major for C major
+    pub fn subdominant_key(&self) -> Self {
+        self.transpose(Step::from(PERFECT_FOURTH))
+    }
+
+    /// Returns the scale tone above a degree of the scale.
+    ///
+    /// Above the last degree the neighbor wraps to the tonic an octave higher.
+    ///
+    /// # Arguments
+    ///
+    /// * `degree` - The scale degree, starting at 1 for the tonic
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the upper neighbor, or `None` if the degree is not in the scale or the
+    /// neighbor is above the MIDI range
+    pub fn upper_neighbor(&self, degree: usize) -> Option<Pitch> {
+        if !(1..=Q::STEPS_LENGTH).contains(&degree) {
+            return None;
+        }
+
+        if degree < Q::STEPS_LENGTH {
+            return self.items().get(degree).copied();
+        }
+
+        let tonic = self.items().first()?.semitones();
+        tonic
+            .checked_add(SEMITONES_IN_OCTAVE)
+            .filter(|semitones| *semitones <= Pitch::MAX.semitones())
+            .map(Pitch::new)
+    }
+
+    /// Returns the scale tone below a degree of the scale.
+    ///
+    /// Below the tonic the neighbor wraps to the last degree an octave lower.
+    ///
+    /// # Arguments
+    ///
+    /// * `degree` - The scale degree, starting at 1 for the tonic
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the lower neighbor, or `None` if the degree is not in the scale or the
+    /// neighbor is below the MIDI range
+    pub fn lower_neighbor(&self, degree: usize) -> Option<Pitch> {
+        if !(1..=Q::STEPS_LENGTH).contains(&degree) {
+            return None;
+        }
+
+        if degree > 1 {
+            return self.items().get(degree - 2).copied();
+        }
+
+        let last = self.items().get(Q::STEPS_LENGTH - 1)?.semitones();
+        last.checked_sub(SEMITONES_IN_OCTAVE).map(Pitch::new)
+    }
+
+    /// Splits a heptatonic scale into its lower and upper tetrachords.
+    ///
+    /// The lower tetrachord holds degrees 1-4 and the upper tetrachord degrees 5-8, the
+    /// last being the tonic an octave higher.
+    ///
+    /// # Returns
+    ///
+    /// The pitches of the lower and the upper tetrachord
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale does not have exactly eight pitches
+    pub fn tetrachords(&self) -> (Vec<Pitch>, Vec<Pitch>) {
+        assert!(
+            N == 8,
+            "Tetrachords need a heptatonic scale with its octave. length: {}",
+            N
+        );
+
+        let (lower, upper) = self.items().split_at(N / 2);
+        (lower.to_vec(), upper.to_vec())
+    }
+
+    /// Returns the equal-tempered frequency of every pitch of the scale.
+    ///
+    /// # Returns
+    ///
+    /// The frequencies in Hz, in the order of the pitches, with A4 tuned to 440 Hz
+    pub fn frequencies_hz(&self) -> Vec<f64> {
+        self.items().iter().map(Pitch::frequency_hz).collect()
+    }
+
+    /// Iterates over the pitches of the scale together with their degree numbers.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of (degree, pitch) pairs, where the tonic is degree 1
+    pub fn enumerate_degrees(&self) -> impl Iterator<Item = (usize, Pitch)> + '_ {
+        self.items()
+            .iter()
+            .enumerate()
+            .map(|(index, pitch)| (index + 1, *pitch))
+    }
+
+    /// Finds every pair of scale tones a tritone apart.
+    ///
+    /// # Returns
+    ///
+    /// The pairs of pitches six semitones apart, each as (lower, upper), ordered by the
+    /// lower pitch's position in the scale
+    pub fn tritones(&self) -> Vec<(Pitch, Pitch)> {
+        let items = self.items();
+        items
+            .iter()
+            .enumerate()
+            .flat_map(|(i, lower)| items[i + 1..].iter().map(move |upper| (*lower, *upper)))
+            .filter(|(lower, upper)| lower.semitones().abs_diff(upper.semitones()) == TRITONE)
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect()
+    }
+
+    /// Returns the leading tone of a heptatonic scale and its resolution to the tonic.
+    ///
+    /// The leading tone is the seventh degree when it lies a half step below the tonic.
+    /// Scales whose seventh degree is a whole step below the tonic, such as natural minor,
+    /// have a subtonic instead and no leading tone.
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the leading tone and the tonic a half step above it, or `None` if the
+    /// scale is not heptatonic or its seventh degree is not a leading tone
+    pub fn leading_tone_resolution(&self) -> Option<(Pitch, Pitch)> {
+        if Q::STEPS_LENGTH != 7 {
+            return None;
+        }
+
+        let tonic = PitchClass::from(*self.items().first()?);
+        let leading_tone = *self.items().get(6)?;
+        let resolution = leading_tone.semitones().checked_add(1).map(Pitch::new)?;
+
+        (PitchClass::from(resolution) == tonic).then_some((leading_tone, resolution))
+    }
+
+    /// Counts the scale tones that fall on black keys.
+    ///
+    /// This estimates how many accidentals are needed to notate the scale. The octave
+    /// repetition of the tonic is counted once.
+    ///
+    /// # Returns
+    ///
+    /// The number of distinct pitch classes of the scale on black keys
+    pub fn accidental_count(&self) -> usize {
+        let pitch_classes = PitchClassSet::from(self);
+        BLACK_KEYS
+            .iter()
+            .filter(|key| pitch_classes.contains(PitchClass::new(**key)))
+            .count()
+    }
+
+    /// Counts the sharps and flats needed to spell the scale.
+    ///
+    /// Unlike [`Scale::accidental_count`], this spells the scale conventionally first (one
+    /// letter name per degree for heptatonic scales), so the sharps and flats are told
+    /// apart. Each letter name is counted once, so the octave repetition of the tonic
+    /// does not add an accidental.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (sharps, flats), e.g. `(1, 0)` for G major
+    pub fn key_signature(&self) -> (u8, u8) {
+        let mut spellings = spell(self.items());
+        spellings.sort_by_key(|spelling| (spelling.letter, spelling.accidental));
+        spellings.dedup_by_key(|spelling| (spelling.letter, spelling.accidental));
+
+        spellings.iter().fold((0, 0), |(sharps, flats), spelling| {
+            match spelling.accidental {
+                1.. => (sharps + 1, flats),
+                ..0 => (sharps, flats + 1),
+                0 => (sharps, flats),
+            }
+        })
+    }
+
+    /// Returns the pitch classes that are not in the scale.
+    ///
+    /// # Returns
+    ///
+    /// The pitch classes missing from the scale, in ascending order from C
+    pub fn complement(&self) -> Vec<PitchClass> {
+        let pitch_classes = PitchClassSet::from(self);
+        (0..SEMITONES_IN_OCTAVE)
+            .map(PitchClass::new)
+            .filter(|pitch_class| !pitch_classes.contains(*pitch_class))
+            .collect()
+    }
+
+    /// Returns the pitches of the scale starting from one of its degrees.
+    ///
+    /// The degrees below the starting degree are moved up an octave so the result stays
+    /// ascending, and the starting pitch is repeated an octave higher at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `degree` - The degree to start on, starting at 1 for the tonic
+    ///
+    /// # Returns
+    ///
+    /// The rotated pitches, from the starting degree up to its octave
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is not a degree of the scale
+    pub fn starting_on(&self, degree: usize) -> Vec<Pitch> {
+        assert!(
+            (1..=Q::STEPS_LENGTH.min(N)).contains(&degree),
+            "Invalid scale degree. degree: {}",
+            degree
+        );
+
+        let octave = Step::new(SEMITONES_IN_OCTAVE);
+        let degrees = &self.items()[..Q::STEPS_LENGTH.min(N)];
+        let (below, from) = degrees.split_at(degree - 1);
+
+        from.iter()
+            .copied()
+            .chain(below.iter().map(|pitch| *pitch + octave))
+            .chain(std::iter::once(from[0] + octave))
+            .collect()
+    }
+
+    /// Builds an ascending arpeggio from selected degrees of the scale.
+    ///
+    /// Same as [`Scale::arpeggio_tiled`] with the chord played once.
+    ///
+    /// # Arguments
+    ///
+    /// * `chord_degrees` - The degrees to play, starting at 1 for the tonic
+    ///
+    /// # Returns
+    ///
+    /// The pitches of the arpeggio, e.g. `[C4, E4, G4, C5]` for `[1, 3, 5]` in C major
+    ///
+    /// # Panics
+    ///
+    /// Panics if a degree is 0
+    pub fn arpeggio(&self, chord_degrees: &[usize]) -> Vec<Pitch> {
+        self.arpeggio_tiled(chord_degrees, 1)
+    }
+
+    /// Builds an ascending arpeggio that repeats the chord over several octaves.
+    ///
+    /// The degrees are played in ascending order whatever order they are given in, and
+    /// duplicates are played once. Degrees past the last degree of the scale continue into
+    /// the next octave, so `[1, 3, 5, 7, 9]` spells a ninth chord. The chord spans as many
+    /// octaves as it takes to reach its highest degree from its lowest one, and each
+    /// repetition starts that many octaves above the previous one: a triad is repeated
+    /// every octave, a ninth chord every two octaves. The arpeggio closes on its lowest
+    /// degree where the next repetition would start. Pitches that would fall above the
+    /// MIDI range are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `chord_degrees` - The degrees to play, starting at 1 for the tonic
+    /// * `times` - How many times the chord is played before the closing pitch
+    ///
+    /// # Returns
+    ///
+    /// The pitches of the arpeggio, e.g. `[C4, E4, G4, C5, E5, G5, C6]` for `[1, 3, 5]`
+    /// played twice in C major, or an empty vector if there are no degrees or `times` is 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if a degree is 0
+    pub fn arpeggio_tiled(&self, chord_degrees: &[usize], times: usize) -> Vec<Pitch> {
+        assert!(
+            !chord_degrees.contains(&0),
+            "Invalid scale degree. degree: 0"
+        );
+
+        let mut degrees = chord_degrees.to_vec();
+        degrees.sort_unstable();
+        degrees.dedup();
+        let (Some(&lowest), Some(&highest)) = (degrees.first(), degrees.last()) else {
+            return Vec::new();
+        };
+        if times == 0 {
+            return Vec::new();
+        }
+
+        let degree_count = Q::STEPS_LENGTH.min(N);
+        let span = ((highest - lowest) / degree_count + 1) * degree_count;
+        let pitch_of = |degree: usize| {
+            let octaves = i8::try_from((degree - 1) / degree_count).ok()?;
+            self.items()[(degree - 1) % degree_count].shift_octave(octaves)
+        };
+
+        (0..times)
+            .flat_map(|time| degrees.iter().map(move |degree| degree + time * span))
+            .chain(std::iter::once(lowest + times * span))
+            .filter_map(pitch_of)
+            .collect()
+    }
+
+    /// Generates a pseudo-random melody that stays in the scale.
+    ///
+    /// The melody starts on the tonic and each following note is picked among the pitches
+    /// of the scale, in any octave, at most `max_leap` away from the previous note. The
+    /// same seed always gives the same melody.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The number of notes in the melody
+    /// * `max_leap` - The largest step allowed between consecutive notes
+    /// * `seed` - The seed of the pseudo-random generator
+    ///
+    /// # Returns
+    ///
+    /// The notes of the melody, or an empty list if `length` is 0
+    pub fn random_walk(&self, length: usize, max_leap: Step, seed: u64) -> Vec<Pitch> {
+        let Some(tonic) = self.items().first().copied() else {
+            return Vec::new();
+        };
+
+        let mut state = seed;
+        std::iter::successors(Some(tonic), |current| {
+            let low = current.semitones().saturating_sub(max_leap.semitones());
+            let high = current
+                .semitones()
+                .saturating_add(max_leap.semitones())
+                .min(Pitch::MAX.semitones());
+            let candidates = self.pitches_in_range(Pitch::new(low), Pitch::new(high));
+            let index = next_random(&mut state) % candidates.len() as u64;
+            candidates.get(index as usize).copied()
+        })
+        .take(length)
+        .collect()
+    }
+
+    /// Returns the keys next to the scale's key on the circle of fifths.
+    ///
+    /// The scale is treated as a major key when its third degree is a major third above
+    /// the tonic, and as a minor key otherwise.
+    ///
+    /// # Returns
+    ///
+    /// The dominant, subdominant and relative keys as (root, is_major) pairs, or an empty
+    /// list if the scale has fewer than three pitches
+    pub fn neighboring_keys(&self) -> Vec<(PitchClass, bool)> {
+        let (Some(tonic), Some(third)) = (self.items().first(), self.items().get(2)) else {
+            return Vec::new();
+        };
+
+        let is_major =
+            third.semitones().checked_sub(tonic.semitones()) == Some(MAJOR_THIRD.semitones());
+        neighboring_keys(PitchClass::from(*tonic), is_major).to_vec()
+    }
+
+    /// Returns the degree of the scale a pitch belongs to, in any octave.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to look up
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the degree, starting at 1 for the tonic, or `None` if the pitch class
+    /// is not in the scale
+    pub fn degree_of(&self, pitch: Pitch) -> Option<usize> {
+        let pitch_class = PitchClass::from(pitch);
+        self.items()
+            .iter()
+            .position(|item| PitchClass::from(*item) == pitch_class)
+            .map(|index| index + 1)
+    }
+
+    /// Returns the functional name of the degree a pitch belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to look up
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the degree name, or `None` if the scale is not heptatonic or the pitch
+    /// class is not in the scale
+    pub fn function_of(&self, pitch: Pitch) -> Option<DegreeName> {
+        if Q::STEPS_LENGTH != 7 {
+            return None;
+        }
+
+        match self.degree_of(pitch)? {
+            1 => Some(DegreeName::Tonic),
+            2 => Some(DegreeName::Supertonic),
+            3 => Some(DegreeName::Mediant),
+            4 => Some(DegreeName::Subdominant),
+            5 => Some(DegreeName::Dominant),
+            6 => Some(DegreeName::Submediant),
+            7 if self.leading_tone_resolution().is_some() => Some(DegreeName::LeadingTone),
+            7 => Some(DegreeName::Subtonic),
+            _ => None,
+        }
+    }
+
+    /// Builds the diatonic triads of a heptatonic scale.
+    ///
+    /// Each triad stacks two thirds of the scale on one of its degrees, wrapping into the
+    /// next octave past the seventh degree.
+    fn diatonic_triads(&self) -> Vec<[Pitch; 3]> {
+        if Q::STEPS_LENGTH != 7 || N < Q::STEPS_LENGTH {
+            return Vec::new();
+        }
+
+        let degrees = &self.items()[..Q::STEPS_LENGTH];
+        let octave = Step::new(SEMITONES_IN_OCTAVE);
+        let tone = |index: usize| {
+            let pitch = degrees[index % degrees.len()];
+            if index < degrees.len() {
+                pitch
+            } else {
+                pitch + octave
+            }
+        };
+
+        (0..degrees.len())
+            .map(|root| [tone(root), tone(root + 2), tone(root + 4)])
+            .collect()
+    }
+
+    /// Lists the diatonic triads of a heptatonic scale that contain a pitch.
+    ///
+    /// Each triad is built by stacking two thirds of the scale on one of its degrees, and
+    /// contains the pitch when one of its tones has the pitch's pitch class.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - The pitch to look for
+    ///
+    /// # Returns
+    ///
+    /// The triads as (root, third, fifth) in ascending order, starting from the one built
+    /// on the lowest degree, or an empty list if the scale is not heptatonic
+    pub fn triads_containing(&self, pitch: Pitch) -> Vec<[Pitch; 3]> {
+        let pitch_class = PitchClass::from(pitch);
+        self.diatonic_triads()
+            .into_iter()
+            .filter(|triad| {
+                triad
+                    .iter()
+                    .any(|tone| PitchClass::from(*tone) == pitch_class)
+            })
+            .collect()
+    }
+
+    /// Labels the diatonic triads of a heptatonic scale with roman numerals.
+    ///
+    /// Major triads use uppercase numerals and minor triads lowercase ones. Diminished
+    /// triads are lowercase with a `°` and augmented triads uppercase with a `+`.
+    ///
+    /// # Returns
+    ///
+    /// One numeral per degree, e.g. `["I", "ii", "iii", "IV", "V", "vi", "vii°"]` for a
+    /// major scale, or an empty list if the scale is not heptatonic
+    pub fn roman_numerals(&self) -> Vec<String> {
+        self.diatonic_triads()
+            .iter()
+            .zip(ROMAN_NUMERALS)
+            .map(|(triad, numeral)| match triad_quality(triad) {
+                Some(TriadQuality::Minor) => numeral.to_lowercase(),
+                Some(TriadQuality::Diminished) => format!("{}°", numeral.to_lowercase()),
+                Some(TriadQuality::Augmented) => format!("{}+", numeral),
+                Some(TriadQuality::Major) | None => numeral.to_string(),
+            })
+            .collect()
+    }
+
+    /// Builds the diatonic triads of a heptatonic scale as chords.
+    ///
+    /// # Returns
+    ///
+    /// One chord per degree, rooted on that degree with the third and fifth above it taken
+    /// from the scale, or an empty list if the scale is not heptatonic
+    pub fn diatonic_chords(&self) -> Vec<Chord> {
+        self.diatonic_triads()
+            .iter()
+            .map(|[root, third, fifth]| {
+                Chord::new(
+                    *root,
+                    vec![
+                        Interval::from(*third - *root),
+                        Interval::from(*fifth - *root),
+                    ],
+                )
+            })
+            .collect()
+    }
+
+    /// Builds the secondary dominant of a degree of the scale.
+    ///
+    /// The secondary dominant is the dominant seventh chord rooted a perfect fifth above
+    /// the target degree, e.g. V7/V in C major is D7.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_degree` - The degree the chord resolves to, starting at 1 for the tonic
+    ///
+    /// # Returns
+    ///
+    /// The dominant seventh chord whose root is a perfect fifth above the target degree
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_degree` is not a degree of the scale
+    pub fn secondary_dominant(&self, target_degree: usize) -> Chord {
+        assert!(
+            (1..=Q::STEPS_LENGTH.min(N)).contains(&target_degree),
+            "Invalid scale degree. degree: {}",
+            target_degree
+        );
+
+        let target = self.items()[target_degree - 1];
+        ChordBuilder::new()
+            .root(target + Step::from(PERFECT_FIFTH))
+            .add_third()
+            .add_fifth()
+            .add_seventh()
+            .build()
+    }
+
+    /// Builds a harmony line a fixed number of scale steps below a melody.
+    ///
+    /// The harmony moves in parallel with the melody inside the scale, so `2` gives
+    /// diatonic thirds and `5` diatonic sixths below each note. Melody notes outside the
+    /// scale are first snapped to the nearest scale pitch as by [`Scale::snap`].
+    ///
+    /// # Arguments
+    ///
+    /// * `melody` - The melody to harmonize
+    /// * `interval_degrees` - The number of scale steps between each melody note and its
+    ///   harmony note
+    ///
+    /// # Returns
+    ///
+    /// One harmony note per melody note, leaving out those that would fall below the
+    /// MIDI range
+    pub fn harmonize(&self, melody: &[Pitch], interval_degrees: usize) -> Vec<Pitch> {
+        let pitches = self.pitches_in_range(C, Pitch::MAX);
+        melody
+            .iter()
+            .filter_map(|note| {
+                let index = pitches.binary_search(&self.snap(*note)).ok()?;
+                index
+                    .checked_sub(interval_degrees)
+                    .map(|index| pitches[index])
+            })
+            .collect()
+    }
+
+    /// Reinterprets the scale under another quality, keeping its pitches.
+    ///
+    /// The pitches are not validated against the new quality, which is treated as a tag.
+    ///
+    /// # Returns
+    ///
+    /// A scale of quality `Q2` holding the same pitches
+    pub fn with_quality<Q2: ScaleQuality>(self) -> ScaleInPitches<Q2, N> {
+        Scale {
+            items: self.items,
+            _quality: PhantomData,
+        }
+    }
+
+    /// Returns the pitches of the scale in reverse order.
+    ///
+    /// Unlike a descending form of a scale, this is the retrograde of the pitch sequence
+    /// used as a compositional transformation.
+    ///
+    /// # Returns
+    ///
+    /// The pitches of the scale from the last to the first
+    pub fn retrograde(&self) -> Vec<Pitch> {
+        self.items().iter().rev().copied().collect()
+    }
+
+    /// Lists every pitch of the scale, in any octave, that fits within an instrument range.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The playing range of the instrument
+    ///
+    /// # Returns
+    ///
+    /// The playable pitches of the scale in ascending order
+    pub fn playable_on(&self, range: &PitchRange) -> Vec<Pitch> {
+        self.pitches_in_range(range.low(), range.high())
+    }
+
+    /// Turns the scale into notes of equal duration, ready to be played as an exercise.
+    ///
+    /// # Arguments
+    ///
+    /// * `each` - The duration of every note
+    ///
+    /// # Returns
+    ///
+    /// One note per pitch of the scale, in order
+    pub fn to_timed_notes(&self, each: Duration) -> Vec<Note> {
+        self.items()
+            .iter()
+            .map(|pitch| Note::new(*pitch, each))
+            .collect()
+    }
+
+    /// Exports the scale in ABC notation.
+    ///
+    /// Pitches in the octave of middle C are written with uppercase letters and the
+    /// octave above with lowercase letters. Lower octaves add a `,` and higher octaves
+    /// add a `'` per octave. Sharps are written as `^` and flats as `_`.
+    ///
+    /// # Returns
+    ///
+    /// The ABC note string, e.g. `"CDEFGABc"` for C major starting on C4
+    pub fn to_abc(&self) -> String {
+        spell(self.items())
+            .into_iter()
+            .map(|spelling| {
+                let accidental = if spelling.accidental >= 0 { "^" } else { "_" };
+                let letter = LETTERS[spelling.letter];
+
+                let (letter, marks) = if spelling.octave <= 4 {
+                    (letter, ",".repeat((4 - spelling.octave) as usize))
+                } else {
+                    (
+                        letter.to_ascii_lowercase(),
+                        "'".repeat((spelling.octave - 5) as usize),
+                    )
+                };
+
+                format!(
+                    "{}{}{}",
+                    accidental.repeat(spelling.accidental.unsigned_abs() as usize),
+                    letter,
+                    marks
+                )
+            })
+            .collect()
+    }
+
+    /// Exports the scale in LilyPond absolute-pitch notation.
+    ///
+    /// Note names are lowercase letters, with `is` appended per sharp and `es` per flat
+    /// (`es` and `as` for E-flat and A-flat). The octave below middle C has no octave
+    /// mark; each octave above adds a `'` and each octave below adds a `,`.
+    ///
+    /// # Returns
+    ///
+    /// The space-separated LilyPond notes, e.g. `"c' d' e' f' g' a' b' c''"` for C major
+    /// starting on C4
+    pub fn to_lilypond(&self) -> String {
+        spell(self.items())
+            .into_iter()
+            .map(|spelling| {
+                let letter = LETTERS[spelling.letter].to_ascii_lowercase();
+                let count = spelling.accidental.unsigned_abs() as usize;
+
+                let accidental = match (letter, spelling.accidental) {
+                    (_, 0) => String::new(),
+                    (_, 1..) => "is".repeat(count),
+                    ('e' | 'a', _) => format!("s{}", "es".repeat(count - 1)),
+                    (_, _) => "es".repeat(count),
+                };
+
+                let marks = if spelling.octave >= 3 {
+                    "'".repeat((spelling.octave - 3) as usize)
+                } else {
+                    ",".repeat((3 - spelling.octave) as usize)
+                };
+
+                format!("{}{}{}", letter, accidental, marks)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl<Q: ScaleQuality + Eq, const N: usize> PartialOrd for ScaleInPitches<Q, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Q: ScaleQuality + Eq, const N: usize> Ord for ScaleInPitches<Q, N> {
+    /// Orders scales by their tonic, then by the remaining pitches in order
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.items().cmp(other.items())
+    }
+}
+
+impl<Q: ScaleQuality, const N: usize> ScaleInPitches<Q, N>
+where
+    [(); N - 1]:,
+{
+    /// Converts the scale into steps and back into pitches from the same tonic.
+    ///
+    /// The steps between consecutive pitches fully describe an ascending scale once its
+    /// tonic is known, so the rebuilt scale is identical to the original. The two are still
+    /// compared, in release builds too, which checks that the step and pitch conversions
+    /// agree; it does not check anything about the scale itself.
+    ///
+    /// # Returns
+    ///
+    /// The scale rebuilt from its steps
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pitches are not in ascending order, since descending pitches cannot
+    /// be described by steps, or if the rebuilt pitches differ from the original ones
+    pub fn round_trip_through_steps(&self) -> Self {
+        assert!(
+            self.items()
+                .windows(2)
+                .all(|pitches| pitches[0] <= pitches[1]),
+            "Pitches must be in ascending order. pitches: {:?}",
+            self.items()
+        );
+
+        let tonic = self.items()[0];
+        let steps: [Step; N - 1] = self.items().into_steps();
+        let pitches: [Pitch; N] = steps.into_pitches(tonic);
+        assert_eq!(
+            &pitches,
+            self.items(),
+            "Round trip through steps changed the pitches"
+        );
+
+        Scale {
+            items: pitches,
+            _quality: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::scales::{blues_scale, harmonicminor_scale, major_scale, naturalminor_scale};
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(major_scale(C4).transpose(WHOLE), major_scale(D4));
+    }
+
+    #[test]
+    fn test_dominant_and_subdominant_keys() {
+        assert_eq!(major_scale(C4).dominant_key(), major_scale(G4));
+        assert_eq!(major_scale(C4).subdominant_key(), major_scale(F4));
+        assert_eq!(
+            naturalminor_scale(A3).dominant_key(),
+            naturalminor_scale(E4)
+        );
+    }
+
+    #[test]
+    fn test_upper_neighbor() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.upper_neighbor(1), Some(D4));
+        assert_eq!(scale.upper_neighbor(7), Some(C5));
+        assert_eq!(scale.upper_neighbor(8), None);
+    }
+
+    #[test]
+    fn test_lower_neighbor() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.lower_neighbor(1), Some(B3));
+        assert_eq!(scale.lower_neighbor(5), Some(F4));
+        assert_eq!(scale.lower_neighbor(0), None);
+    }
+
+    #[test]
+    fn test_round_trip_through_steps() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.round_trip_through_steps(), scale);
+        let scale = blues_scale(A3);
+        assert_eq!(scale.round_trip_through_steps(), scale);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_round_trip_through_steps_of_descending_pitches() {
+        let descending: ScaleInPitches<TagQuality, 8> =
+            Scale::new([C5, B4, A4, G4, F4, E4, D4, C4]);
+        descending.round_trip_through_steps();
+    }
+
+    #[test]
+    fn test_tetrachords() {
+        let (lower, upper) = major_scale(C4).tetrachords();
+        assert_eq!(lower, vec![C4, D4, E4, F4]);
+        assert_eq!(upper, vec![G4, A4, B4, C5]);
+        assert_eq!(melodic_intervals(&lower), melodic_intervals(&upper));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tetrachords_of_hexatonic_scale() {
+        blues_scale(C4).tetrachords();
+    }
+
+    #[test]
+    fn test_frequencies_hz() {
+        let frequencies = major_scale(A4).frequencies_hz();
+        assert_eq!(frequencies.len(), 8);
+        assert_eq!(frequencies.first(), Some(&440.0));
+        assert_eq!(frequencies.last(), Some(&880.0));
+    }
+
+    #[test]
+    fn test_enumerate_degrees() {
+        let scale = major_scale(C4);
+        let mut degrees = scale.enumerate_degrees();
+        assert_eq!(degrees.next(), Some((1, C4)));
+        assert_eq!(degrees.next(), Some((2, D4)));
+        assert_eq!(scale.enumerate_degrees().last(), Some((8, C5)));
+    }
+
+    #[test]
+    fn test_tritones() {
+        assert_eq!(major_scale(C4).tritones(), vec![(F4, B4)]);
+        assert_eq!(
+            blues_scale(C4).tritones(),
+            vec![(C4, FSHARP4), (FSHARP4, C5)]
+        );
+    }
+
+    #[test]
+    fn test_leading_tone_resolution() {
+        assert_eq!(major_scale(C4).leading_tone_resolution(), Some((B4, C5)));
+        assert_eq!(
+            harmonicminor_scale(A3).leading_tone_resolution(),
+            Some((GSHARP4, A4))
+        );
+    }
+
+    #[test]
+    fn test_no_leading_tone() {
+        assert_eq!(naturalminor_scale(A3).leading_tone_resolution(), None);
+        assert_eq!(blues_scale(C4).leading_tone_resolution(), None);
+    }
+
+    #[test]
+    fn test_accidental_count() {
+        assert_eq!(major_scale(C4).accidental_count(), 0);
+        assert_eq!(major_scale(G4).accidental_count(), 1);
+        assert_eq!(major_scale(FSHARP4).accidental_count(), 5);
+    }
+
+    #[test]
+    fn test_key_signature() {
+        assert_eq!(major_scale(G4).key_signature(), (1, 0));
+        assert_eq!(major_scale(C4).key_signature(), (0, 0));
+        assert_eq!(major_scale(F4).key_signature(), (0, 1));
+        assert_eq!(naturalminor_scale(C4).key_signature(), (0, 3));
+    }
+
+    #[test]
+    fn test_complement() {
+        let black_keys: Vec<PitchClass> = [CSHARP4, DSHARP4, FSHARP4, GSHARP4, ASHARP4]
+            .into_iter()
+            .map(PitchClass::from)
+            .collect();
+        assert_eq!(major_scale(C4).complement(), black_keys);
+        assert_eq!(blues_scale(C4).complement().len(), 6);
+    }
+
+    #[test]
+    fn test_starting_on() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.starting_on(6), vec![A4, B4, C5, D5, E5, F5, G5, A5]);
+        assert_eq!(scale.starting_on(1), scale.to_vec());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_starting_on_invalid_degree() {
+        major_scale(C4).starting_on(8);
+    }
+
+    #[test]
+    fn test_arpeggio() {
+        assert_eq!(major_scale(C4).arpeggio(&[1, 3, 5]), vec![C4, E4, G4, C5]);
+        assert_eq!(
+            major_scale(C4).arpeggio(&[1, 3, 5, 7, 9]),
+            vec![C4, E4, G4, B4, D5, C6]
+        );
+        assert_eq!(major_scale(C4).arpeggio(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_arpeggio_sorts_degrees() {
+        assert_eq!(major_scale(C4).arpeggio(&[3, 5, 1]), vec![C4, E4, G4, C5]);
+        assert_eq!(
+            major_scale(C4).arpeggio(&[5, 1, 3, 1]),
+            vec![C4, E4, G4, C5]
+        );
+    }
+
+    #[test]
+    fn test_arpeggio_tiled() {
+        let scale = major_scale(C4);
+        assert_eq!(
+            scale.arpeggio_tiled(&[1, 3, 5], 2),
+            vec![C4, E4, G4, C5, E5, G5, C6]
+        );
+        assert_eq!(
+            scale.arpeggio_tiled(&[1, 3, 5, 7, 9], 2),
+            vec![C4, E4, G4, B4, D5, C6, E6, G6, B6, D7, C8]
+        );
+        assert_eq!(scale.arpeggio_tiled(&[5, 8], 2), vec![G4, C5, G5, C6, G6]);
+        assert_eq!(scale.arpeggio_tiled(&[1, 3, 5], 0), vec![]);
+    }
+
+    #[test]
+    fn test_random_walk() {
+        let scale = major_scale(C4);
+        let melody = scale.random_walk(32, MAJOR_THIRD.into(), 7);
+
+        assert_eq!(melody.len(), 32);
+        assert_eq!(melody[0], C4);
+        assert_eq!(melody, scale.random_walk(32, MAJOR_THIRD.into(), 7));
+        assert!(
+            melody
+                .iter()
+                .all(|pitch| scale.contains_pitch_class(PitchClass::from(*pitch)))
+        );
+        assert!(
+            melody
+                .windows(2)
+                .all(|notes| notes[0].semitones().abs_diff(notes[1].semitones()) <= 4)
+        );
+        assert!(scale.random_walk(0, WHOLE, 7).is_empty());
+    }
+
+    #[test]
+    fn test_pitch_class_mask() {
+        let scale = major_scale(C4);
+        let mask = scale.pitch_class_mask();
+        assert_eq!(mask, 0b1010_1011_0101);
+
+        let in_scale = (0..=Pitch::MAX.semitones())
+            .map(Pitch::new)
+            .filter(|pitch| mask & 1 << PitchClass::from(*pitch).semitones() != 0)
+            .count();
+        assert_eq!(in_scale, scale.pitches_in_range(C, Pitch::MAX).len());
+    }
+
+    #[test]
+    fn test_contains_pitch_class() {
+        let scale = major_scale(G4);
+        assert!(scale.contains_pitch_class(PitchClass::new(6)));
+        assert!(!scale.contains_pitch_class(PitchClass::new(5)));
+    }
+
+    #[test]
+    fn test_masked_scale() {
+        let scale = major_scale(C4);
+        let masked = MaskedScale::from(major_scale(C4));
+        assert_eq!(masked.pitch_class_mask(), 0b1010_1011_0101);
+        assert_eq!(masked.scale(), &scale);
+
+        let lookups = (0..1200u16)
+            .map(|i| PitchClass::new((i % 12) as u8))
+            .filter(|pitch_class| masked.contains_pitch_class(*pitch_class))
+            .count();
+        assert_eq!(lookups, 700);
+        assert!(
+            (0..12)
+                .map(PitchClass::new)
+                .all(|pc| masked.contains_pitch_class(pc) == scale.contains_pitch_class(pc))
+        );
+    }
+
+    #[test]
+    fn test_neighboring_keys() {
+        let keys = major_scale(C4).neighboring_keys();
+        assert!(keys.contains(&(PitchClass::new(7), true)));
+        assert!(keys.contains(&(PitchClass::new(5), true)));
+        assert!(keys.contains(&(PitchClass::new(9), false)));
+        assert_eq!(keys.len(), 3);
+    }
+
+    #[test]
+    fn test_neighboring_keys_of_minor_scale() {
+        let keys = naturalminor_scale(A3).neighboring_keys();
+        assert_eq!(
+            keys,
+            vec![
+                (PitchClass::new(4), false),
+                (PitchClass::new(2), false),
+                (PitchClass::new(0), true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_degree_of() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.degree_of(C4), Some(1));
+        assert_eq!(scale.degree_of(C6), Some(1));
+        assert_eq!(scale.degree_of(A2), Some(6));
+        assert_eq!(scale.degree_of(FSHARP4), None);
+    }
+
+    #[test]
+    fn test_function_of() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.function_of(G4), Some(DegreeName::Dominant));
+        assert_eq!(scale.function_of(F3), Some(DegreeName::Subdominant));
+        assert_eq!(scale.function_of(B4), Some(DegreeName::LeadingTone));
+        assert_eq!(scale.function_of(CSHARP4), None);
+        assert_eq!(
+            naturalminor_scale(A3).function_of(G4),
+            Some(DegreeName::Subtonic)
+        );
+        assert_eq!(blues_scale(C4).function_of(C4), None);
+    }
+
+    #[test]
+    fn test_triads_containing() {
+        assert_eq!(
+            major_scale(C4).triads_containing(C4),
+            vec![[C4, E4, G4], [F4, A4, C5], [A4, C5, E5]]
+        );
+        assert!(major_scale(C4).triads_containing(FSHARP4).is_empty());
+        assert!(blues_scale(C4).triads_containing(C4).is_empty());
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct TagQuality;
+
+    impl ScaleQuality for TagQuality {
+        const STEPS_LENGTH: usize = 7;
+        type Pattern = [Step; 7];
+        const STEPS_PATTERN: Self::Pattern = [HALF; 7];
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        assert_eq!(
+            major_scale(C4).roman_numerals(),
+            ["I", "ii", "iii", "IV", "V", "vi", "vii°"]
+        );
+        assert_eq!(
+            harmonicminor_scale(A4).roman_numerals(),
+            ["i", "ii°", "III+", "iv", "V", "VI", "vii°"]
+        );
+        assert!(blues_scale(C4).roman_numerals().is_empty());
+    }
+
+    #[test]
+    fn test_diatonic_chords() {
+        let chords = major_scale(C4).diatonic_chords();
+
+        let roots: Vec<Pitch> = chords.iter().map(Chord::root).collect();
+        assert_eq!(roots, vec![C4, D4, E4, F4, G4, A4, B4]);
+
+        let qualities: Vec<Option<TriadQuality>> = chords
+            .iter()
+            .map(|chord| triad_quality(&chord.pitches().try_into().unwrap()))
+            .collect();
+        assert_eq!(
+            qualities,
+            [
+                TriadQuality::Major,
+                TriadQuality::Minor,
+                TriadQuality::Minor,
+                TriadQuality::Major,
+                TriadQuality::Major,
+                TriadQuality::Minor,
+                TriadQuality::Diminished,
+            ]
+            .map(Some)
+        );
+        assert!(blues_scale(C4).diatonic_chords().is_empty());
+    }
+
+    #[test]
+    fn test_secondary_dominant() {
+        let chord = major_scale(C4).secondary_dominant(5);
+        assert_eq!(PitchClass::from(chord.root()), PitchClass::from(D4));
+        assert_eq!(
+            chord.intervals(),
+            [MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH]
+        );
+        assert_eq!(chord.pitches(), vec![D5, FSHARP5, A5, C6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_secondary_dominant_invalid_degree() {
+        major_scale(C4).secondary_dominant(0);
+    }
+
+    #[test]
+    fn test_harmonize_in_thirds() {
+        let scale = major_scale(C4);
+        assert_eq!(
+            scale.harmonize(&[E4, F4, G4, A4, C5], 2),
+            vec![C4, D4, E4, F4, A4]
+        );
+        assert_eq!(scale.harmonize(&[C4, FSHARP4], 5), vec![E3, B3]);
+        assert_eq!(scale.harmonize(&[C, D], 2), vec![]);
+    }
+
+    #[test]
+    fn test_with_quality() {
+        let scale: ScaleInPitches<TagQuality, 8> = major_scale(C4).with_quality();
+        assert_eq!(scale.items(), major_scale(C4).items());
+        assert_eq!(
+            format!("{:?}", scale),
+            format!("TagScale{:?}", scale.items())
+        );
+    }
+
+    #[test]
+    fn test_retrograde() {
+        assert_eq!(
+            major_scale(C4).retrograde(),
+            vec![C5, B4, A4, G4, F4, E4, D4, C4]
+        );
+    }
+
+    #[test]
+    fn test_to_timed_notes() {
+        let scale = major_scale(C4);
+        let notes = scale.to_timed_notes(EIGHTH_NOTE);
+
+        assert_eq!(notes.len(), scale.len());
+        assert!(
+            notes
+                .iter()
+                .zip(scale.pitches())
+                .all(|(note, pitch)| note.pitch() == *pitch && note.duration() == EIGHTH_NOTE)
+        );
+    }
+
+    #[test]
+    fn test_to_abc_c_major() {
+        assert_eq!(major_scale(C4).to_abc(), "CDEFGABc");
+    }
+
+    #[test]
+    fn test_to_abc_sharps() {
+        assert_eq!(major_scale(G4).to_abc(), "GABcde^fg");
+        assert_eq!(major_scale(D4).to_abc(), "DE^FGAB^cd");
+    }
+
+    #[test]
+    fn test_to_abc_flats() {
+        assert_eq!(major_scale(F4).to_abc(), "FGA_Bcdef");
+        assert_eq!(naturalminor_scale(C4).to_abc(), "CD_EFG_A_Bc");
+    }
+
+    #[test]
+    fn test_to_abc_octave_marks() {
+        assert_eq!(major_scale(C3).to_abc(), "C,D,E,F,G,A,B,C");
+        assert_eq!(major_scale(C6).to_abc(), "c'd'e'f'g'a'b'c''");
+    }
+
+    #[test]
+    fn test_snap_in_scale_pitch_is_unchanged() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.snap(E4), E4);
+        assert_eq!(scale.snap(D6), D6);
+    }
+
+    #[test]
+    fn test_snap_ties_round_up() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.snap(FSHARP4), G4);
+        assert_eq!(scale.snap(CSHARP3), D3);
+    }
+
+    #[test]
+    fn test_snap_nearest() {
+        let scale = major_scale(D4);
+        assert_eq!(scale.snap(C4), CSHARP4);
+        assert_eq!(scale.snap(G4), G4);
+        assert_eq!(scale.snap(F4), FSHARP4);
+    }
+
+    #[test]
+    fn test_playable_on() {
+        let pitches = major_scale(C4).playable_on(&FLUTE_RANGE);
+        assert_eq!(pitches.len(), 22);
+        assert_eq!(pitches.first(), Some(&C4));
+        assert_eq!(pitches.last(), Some(&C7));
+        assert!(pitches.contains(&B6));
+        assert!(!pitches.contains(&FSHARP5));
+    }
+
+    #[test]
+    fn test_snap_frequency() {
+        let scale = major_scale(C4);
+        assert_eq!(scale.snap_frequency(445.0), A4);
+        assert_eq!(scale.snap_frequency(370.0), G4);
+        assert_eq!(scale.snap_frequency(1.0), C);
+    }
+
+    #[test]
+    fn test_pitches_in_range() {
+        let scale = major_scale(C4);
+        assert_eq!(
+            scale.pitches_in_range(C3, C4),
+            vec![C3, D3, E3, F3, G3, A3, B3, C4]
+        );
+    }
+
+    #[test]
+    fn test_pitches_in_range_spans_octaves() {
+        let scale = major_scale(G4);
+        assert_eq!(
+            scale.pitches_in_range(E3, A4),
+            vec![E3, FSHARP3, G3, A3, B3, C4, D4, E4, FSHARP4, G4, A4]
+        );
+    }
+
+    #[test]
+    fn test_pitches_in_empty_range() {
+        assert!(major_scale(C4).pitches_in_range(C4, B3).is_empty());
+    }
+
+    #[test]
+    fn test_sort_by_tonic() {
+        let mut scales = vec![major_scale(C4), major_scale(G4), major_scale(D4)];
+        scales.sort();
+        assert_eq!(
+            scales,
+            vec![major_scale(C4), major_scale(D4), major_scale(G4)]
+        );
+    }
+
+    #[test]
+    fn test_compare_same_tonic() {
+        let a = major_scale(C4);
+        let b = major_scale(C4).map(|pitch| if *pitch == B4 { BFLAT4 } else { *pitch });
+        assert!(b < a);
+        assert_eq!(a.cmp(&major_scale(C4)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_to_lilypond_c_major() {
+        assert_eq!(major_scale(C4).to_lilypond(), "c' d' e' f' g' a' b' c''");
+    }
+
+    #[test]
+    fn test_to_lilypond_accidentals() {
+        assert_eq!(
+            major_scale(D4).to_lilypond(),
+            "d' e' fis' g' a' b' cis'' d''"
+        );
+        assert_eq!(
+            naturalminor_scale(C4).to_lilypond(),
+            "c' d' es' f' g' as' bes' c''"
+        );
+    }
+
+    #[test]
+    fn test_to_lilypond_octave_marks() {
+        assert_eq!(major_scale(C3).to_lilypond(), "c d e f g a b c'");
+        assert_eq!(major_scale(C2).to_lilypond(), "c, d, e, f, g, a, b, c");
+    }
+}
@@ -0,0 +1,94 @@
+//! Operations on scales represented as steps
+//!
+//! This module provides the `ScaleInSteps` alias for any scale stored as steps, and the
+//! `AnonScaleInSteps` alias for step scales that do not need a dedicated quality type,
+//! which is convenient for ad-hoc analysis.
+
+use std::marker::PhantomData;
+
+use super::{NonEmpty, Scale, ScaleQuality};
+use crate::core::Step;
+use crate::{HALF, UNISON, WHOLE};
+
+/// A scale of any quality represented by its steps
+pub type ScaleInSteps<Q, const N: usize> = Scale<Q, Step, N>;
+
+impl<Q: ScaleQuality, const N: usize> ScaleInSteps<Q, N> {
+    /// Returns the step pattern of the scale in W/H shorthand.
+    ///
+    /// Whole steps are written as `W` and half steps as `H`. Steps of any other size are
+    /// written as their number of semitones, e.g. `3` for an augmented second.
+    ///
+    /// # Returns
+    ///
+    /// The pattern string, e.g. `"WWHWWWH"` for the major scale
+    pub fn pattern_string(&self) -> String {
+        self.items()
+            .iter()
+            .map(|step| match *step {
+                WHOLE => "W".to_string(),
+                HALF => "H".to_string(),
+                _ => step.semitones().to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Quality marker for scales that only know their number of steps
+///
+/// Its step pattern is a placeholder of `N` unison steps; the actual steps are the
+/// elements of the scale.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenericQuality<const N: usize>;
+
+impl<const N: usize> ScaleQuality for GenericQuality<N> {
+    const STEPS_LENGTH: usize = N;
+    type Pattern = [Step; N];
+    const STEPS_PATTERN: Self::Pattern = [UNISON; N];
+}
+
+/// A scale represented by its steps without a dedicated quality type
+pub type AnonScaleInSteps<const N: usize> = ScaleInSteps<GenericQuality<N>, N>;
+
+impl<const N: usize> From<[Step; N]> for AnonScaleInSteps<N> {
+    /// Creates an anonymous scale from its steps
+    ///
+    /// An empty array of steps is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use no_surprises::prelude::*;
+    /// use no_surprises::scales::AnonScaleInSteps;
+    ///
+    /// let scale = AnonScaleInSteps::<0>::from([]);
+    /// ```
+    fn from(steps: [Step; N]) -> Self {
+        let () = NonEmpty::<N>::CHECK;
+
+        Scale {
+            items: steps,
+            _quality: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::scales::{harmonicminor_scale_in_steps, major_scale_in_steps};
+
+    #[test]
+    fn test_pattern_string() {
+        assert_eq!(major_scale_in_steps().pattern_string(), "WWHWWWH");
+        assert_eq!(harmonicminor_scale_in_steps().pattern_string(), "WHWWH3H");
+    }
+
+    #[test]
+    fn test_anonymous_scale_to_pitches() {
+        let scale = AnonScaleInSteps::from([WHOLE, WHOLE, WHOLE_AND_HALF, WHOLE, WHOLE_AND_HALF]);
+        assert_eq!(scale.len(), 5);
+        assert_eq!(scale.as_pitches(C4), vec![C4, D4, E4, G4, A4, C5]);
+    }
+}